@@ -1,4 +1,4 @@
-use gh_cli_rs::GhClient;
+use gh_cli_rs::{GhClient, Visibility};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client = GhClient::new();
@@ -7,7 +7,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .repo()
         .create("gh-cli-rs")
         .description("A wrapper for GitHub CLI in Rust")
-        .public()
+        .visibility(Visibility::Public)
         .with_readme()
         .execute()
     {
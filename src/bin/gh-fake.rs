@@ -0,0 +1,44 @@
+//! Fake gh binary answering from a scenario file, see `gh_cli_rs::testing`
+
+use gh_cli_rs::testing::{Scenario, NO_MATCH_EXIT_CODE, SCENARIO_ENV};
+use std::io::{self, Write};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let path = match std::env::var(SCENARIO_ENV) {
+        Ok(path) => path,
+        Err(_) => {
+            eprintln!("gh-fake: {} is not set", SCENARIO_ENV);
+            process::exit(NO_MATCH_EXIT_CODE);
+        }
+    };
+
+    let scenario = match Scenario::load(&path) {
+        Ok(scenario) => scenario,
+        Err(e) => {
+            eprintln!("gh-fake: failed to load {}: {}", path, e);
+            process::exit(NO_MATCH_EXIT_CODE);
+        }
+    };
+
+    let rule = match scenario.find(&args) {
+        Some(rule) => rule,
+        None => {
+            eprintln!("gh-fake: no rule matches: gh {}", args.join(" "));
+            process::exit(NO_MATCH_EXIT_CODE);
+        }
+    };
+
+    if rule.delay_ms > 0 {
+        thread::sleep(Duration::from_millis(rule.delay_ms));
+    }
+
+    // Ignore broken pipes: the caller may not read the output
+    let _ = io::stdout().write_all(rule.stdout.as_bytes());
+    let _ = io::stderr().write_all(rule.stderr.as_bytes());
+    process::exit(rule.exit_code);
+}
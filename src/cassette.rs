@@ -0,0 +1,334 @@
+use crate::error::{GhError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Output;
+use std::sync::Mutex;
+
+/// Placeholder written instead of secret environment values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A single recorded gh invocation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// Arguments passed to gh
+    pub args: Vec<String>,
+    /// Extra environment variables, with secrets redacted
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    /// Data written to stdin, if any
+    #[serde(default)]
+    pub stdin: Option<String>,
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: i32,
+}
+
+impl Interaction {
+    pub(crate) fn from_output(
+        args: &[String],
+        env: &[(String, String)],
+        stdin: Option<&str>,
+        output: &Output,
+    ) -> Self {
+        Self {
+            args: args.to_vec(),
+            env: env
+                .iter()
+                .map(|(key, value)| (key.clone(), redact(key, value)))
+                .collect(),
+            stdin: stdin.map(str::to_string),
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            exit_code: output.status.code().unwrap_or(-1),
+        }
+    }
+
+    /// Convert the recorded outcome into the result the executor would return
+    pub(crate) fn to_result(&self) -> Result<String> {
+        if self.exit_code == 0 {
+            Ok(self.stdout.clone())
+        } else {
+            Err(GhError::CommandFailed {
+                code: self.exit_code,
+                stderr: self.stderr.clone(),
+            })
+        }
+    }
+}
+
+/// Whether a cassette records real invocations or replays recorded ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Run gh and append every invocation to the cassette file
+    Record,
+    /// Serve responses from the cassette without running gh
+    Replay,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// On-disk format of a cassette, chosen by file extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Json,
+    /// `.yaml` or `.yml`, requires the `yaml` feature
+    Yaml,
+}
+
+impl Format {
+    fn of(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => Format::Yaml,
+            _ => Format::Json,
+        }
+    }
+
+    fn decode(self, content: &str) -> Result<CassetteFile> {
+        match self {
+            Format::Json => Ok(serde_json::from_str(content)?),
+            #[cfg(feature = "yaml")]
+            Format::Yaml => Ok(serde_yaml::from_str(content)?),
+            #[cfg(not(feature = "yaml"))]
+            Format::Yaml => Err(yaml_disabled()),
+        }
+    }
+
+    fn encode(self, file: &CassetteFile) -> Result<String> {
+        match self {
+            Format::Json => Ok(serde_json::to_string_pretty(file)?),
+            #[cfg(feature = "yaml")]
+            Format::Yaml => Ok(serde_yaml::to_string(file)?),
+            #[cfg(not(feature = "yaml"))]
+            Format::Yaml => Err(yaml_disabled()),
+        }
+    }
+}
+
+#[cfg(not(feature = "yaml"))]
+fn yaml_disabled() -> GhError {
+    GhError::FeatureDisabled("YAML cassettes", "yaml")
+}
+
+/// Record/replay store for gh invocations
+///
+/// Cassettes are JSON files, or YAML files for `.yaml`/`.yml` paths with the
+/// `yaml` feature enabled.
+///
+/// # Example
+/// ```no_run
+/// # use gh_cli_rs::{Cassette, GhClient};
+/// // Record once against the real gh...
+/// let client = GhClient::builder()
+///     .cassette(Cassette::record("tests/cassettes/pr_list.json"))
+///     .build();
+/// client.pr().list().execute().unwrap();
+///
+/// // ...then replay offline in tests
+/// let client = GhClient::builder()
+///     .cassette(Cassette::replay("tests/cassettes/pr_list.json").unwrap())
+///     .build();
+/// client.pr().list().execute().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Cassette {
+    mode: CassetteMode,
+    path: Option<PathBuf>,
+    interactions: Mutex<Vec<Interaction>>,
+    used: Mutex<Vec<bool>>,
+}
+
+impl Cassette {
+    /// Create a cassette that records to the given file, overwriting it
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self {
+            mode: CassetteMode::Record,
+            path: Some(path.as_ref().to_path_buf()),
+            interactions: Mutex::new(Vec::new()),
+            used: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Load a cassette file for replay
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self> {
+        let content = fs::read_to_string(path.as_ref())?;
+        let file = Format::of(path.as_ref()).decode(&content)?;
+        let mut cassette = Self::from_interactions(file.interactions);
+        cassette.path = Some(path.as_ref().to_path_buf());
+        Ok(cassette)
+    }
+
+    /// Create a replay cassette from in-memory interactions
+    pub fn from_interactions(interactions: Vec<Interaction>) -> Self {
+        Self {
+            mode: CassetteMode::Replay,
+            path: None,
+            used: Mutex::new(vec![false; interactions.len()]),
+            interactions: Mutex::new(interactions),
+        }
+    }
+
+    /// Get the cassette mode
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Get a snapshot of the recorded interactions
+    pub fn interactions(&self) -> Vec<Interaction> {
+        lock(&self.interactions).clone()
+    }
+
+    /// Number of recorded interactions not yet served in replay mode
+    pub fn remaining(&self) -> usize {
+        lock(&self.used).iter().filter(|used| !**used).count()
+    }
+
+    /// Append an interaction and write the cassette file
+    pub(crate) fn push(&self, interaction: Interaction) -> Result<()> {
+        let mut interactions = lock(&self.interactions);
+        interactions.push(interaction);
+        lock(&self.used).push(true);
+
+        if let Some(path) = &self.path {
+            let file = CassetteFile {
+                interactions: interactions.clone(),
+            };
+            fs::write(path, Format::of(path).encode(&file)?)?;
+        }
+        Ok(())
+    }
+
+    /// Find the first unused interaction matching the invocation
+    pub(crate) fn find(&self, args: &[String], stdin: Option<&str>) -> Result<Interaction> {
+        let interactions = lock(&self.interactions);
+        let mut used = lock(&self.used);
+
+        let index = interactions
+            .iter()
+            .enumerate()
+            .position(|(i, interaction)| {
+                !used[i] && interaction.args == args && interaction.stdin.as_deref() == stdin
+            })
+            .ok_or_else(|| GhError::UnexpectedCommand(args.join(" ")))?;
+
+        used[index] = true;
+        Ok(interactions[index].clone())
+    }
+}
+
+/// Hide values of environment variables that look like credentials
+fn redact(key: &str, value: &str) -> String {
+    let key = key.to_uppercase();
+    if ["TOKEN", "SECRET", "PASSWORD", "KEY"]
+        .iter()
+        .any(|s| key.contains(s))
+    {
+        REDACTED.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::GhExecutor;
+    use std::sync::Arc;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_record_then_replay() {
+        let path =
+            std::env::temp_dir().join(format!("gh-cli-rs-cassette-{}.json", std::process::id()));
+
+        // `echo` stands in for gh so recording works without it installed
+        let recorder = GhExecutor::new("echo".to_string())
+            .with_env("GH_TOKEN", "secret")
+            .with_env("GH_HOST", "github.com")
+            .with_cassette(Cassette::record(&path));
+        let recorded = recorder.execute(&args(&["pr", "list"])).unwrap();
+        assert_eq!(recorded, "pr list\n");
+
+        let cassette = Cassette::replay(&path).unwrap();
+        let interaction = &cassette.interactions()[0];
+        assert_eq!(interaction.env["GH_TOKEN"], REDACTED);
+        assert_eq!(interaction.env["GH_HOST"], "github.com");
+
+        let replayer = GhExecutor::new("/nonexistent/gh".to_string()).with_cassette(cassette);
+        assert_eq!(replayer.execute(&args(&["pr", "list"])).unwrap(), recorded);
+        assert_eq!(replayer.cassette.as_ref().map(|c| c.remaining()), Some(0));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_cassette_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("gh-cli-rs-cassette-{}.yaml", std::process::id()));
+
+        let recorder = GhExecutor::new("cat".to_string()).with_cassette(Cassette::record(&path));
+        recorder
+            .execute_with_input(&[], "title: \"quoted\"\nbody")
+            .unwrap();
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .starts_with("interactions:"));
+
+        let cassette = Cassette::replay(&path).unwrap();
+        assert_eq!(
+            cassette.interactions(),
+            recorder.cassette.unwrap().interactions()
+        );
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(not(feature = "yaml"))]
+    #[test]
+    fn test_yaml_cassette_requires_feature() {
+        assert!(matches!(
+            Format::Yaml.decode("interactions: []"),
+            Err(GhError::FeatureDisabled(_, "yaml"))
+        ));
+    }
+
+    #[test]
+    fn test_replay_rejects_unexpected_commands() {
+        let cassette = Arc::new(Cassette::from_interactions(vec![Interaction {
+            args: args(&["issue", "close", "1"]),
+            env: BTreeMap::new(),
+            stdin: None,
+            stdout: String::new(),
+            stderr: "HTTP 404".to_string(),
+            exit_code: 1,
+        }]));
+
+        assert!(matches!(
+            cassette.find(&args(&["issue", "close", "2"]), None),
+            Err(GhError::UnexpectedCommand(_))
+        ));
+        assert!(matches!(
+            cassette
+                .find(&args(&["issue", "close", "1"]), None)
+                .unwrap()
+                .to_result(),
+            Err(GhError::CommandFailed { code: 1, .. })
+        ));
+        // Each interaction is served only once
+        assert!(cassette
+            .find(&args(&["issue", "close", "1"]), None)
+            .is_err());
+    }
+}
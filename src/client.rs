@@ -1,7 +1,32 @@
-use crate::commands::{issue::IssueCommands, pr::PrCommands, repo::RepoCommands};
+use crate::cassette::Cassette;
+use crate::commands::{
+    alias::AliasCommands,
+    api::ApiCommands,
+    attestation::AttestationCommands,
+    browse::BrowseCommand,
+    cache::CacheCommands,
+    codespace::CodespaceCommands,
+    config::ConfigCommands,
+    extension::{ExtensionCommands, ExtensionExecCommand},
+    gist::GistCommands,
+    gpg_key::GpgKeyCommands,
+    issue::IssueCommands,
+    label::LabelCommands,
+    org::OrgCommands,
+    pr::PrCommands,
+    project::ProjectCommands,
+    repo::RepoCommands,
+    ruleset::RulesetCommands,
+    search::SearchCommands,
+    ssh_key::SshKeyCommands,
+    status::StatusCommand,
+};
 use crate::error::Result;
 use crate::executor::GhExecutor;
+use crate::limiter::RateLimiter;
+use crate::retry::RetryPolicy;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Main GitHub CLI client
 /// This is the entry point for all GitHub CLI operations
@@ -48,18 +73,144 @@ impl GhClient {
     pub fn issue(&self) -> IssueCommands {
         IssueCommands::new(self.executor.clone())
     }
+
+    /// Access gist commands
+    pub fn gist(&self) -> GistCommands {
+        GistCommands::new(self.executor.clone())
+    }
+
+    /// Access organization commands
+    pub fn org(&self) -> OrgCommands {
+        OrgCommands::new(self.executor.clone())
+    }
+
+    /// Access label commands
+    pub fn label(&self) -> LabelCommands {
+        LabelCommands::new(self.executor.clone())
+    }
+
+    /// Access project (v2) commands
+    pub fn project(&self) -> ProjectCommands {
+        ProjectCommands::new(self.executor.clone())
+    }
+
+    /// Access codespace commands
+    pub fn codespace(&self) -> CodespaceCommands {
+        CodespaceCommands::new(self.executor.clone())
+    }
+
+    /// Access Actions cache commands
+    pub fn cache(&self) -> CacheCommands {
+        CacheCommands::new(self.executor.clone())
+    }
+
+    /// Access ruleset commands
+    pub fn ruleset(&self) -> RulesetCommands {
+        RulesetCommands::new(self.executor.clone())
+    }
+
+    /// Access artifact attestation commands
+    pub fn attestation(&self) -> AttestationCommands {
+        AttestationCommands::new(self.executor.clone())
+    }
+
+    /// Access search commands
+    pub fn search(&self) -> SearchCommands {
+        SearchCommands::new(self.executor.clone())
+    }
+
+    /// Show assigned work, review requests, mentions and repository activity
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// let status = client.status().org("octo").exclude("octo/sandbox").fetch();
+    /// ```
+    pub fn status(&self) -> StatusCommand {
+        StatusCommand::new(self.executor.clone())
+    }
+
+    /// Access SSH key commands
+    pub fn ssh_key(&self) -> SshKeyCommands {
+        SshKeyCommands::new(self.executor.clone())
+    }
+
+    /// Access GPG key commands
+    pub fn gpg_key(&self) -> GpgKeyCommands {
+        GpgKeyCommands::new(self.executor.clone())
+    }
+
+    /// Access gh configuration commands
+    pub fn config(&self) -> ConfigCommands {
+        ConfigCommands::new(self.executor.clone())
+    }
+
+    /// Access alias commands
+    pub fn alias(&self) -> AliasCommands {
+        AliasCommands::new(self.executor.clone())
+    }
+
+    /// Access extension commands
+    pub fn extension(&self) -> ExtensionCommands {
+        ExtensionCommands::new(self.executor.clone())
+    }
+
+    /// Run an installed extension (`gh <name> <args>`)
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// let output = client
+    ///     .extension_exec("deploy", &["--env", "staging"])
+    ///     .execute();
+    /// ```
+    pub fn extension_exec(&self, name: &str, args: &[&str]) -> ExtensionExecCommand {
+        ExtensionExecCommand::new(self.executor.clone(), name, args)
+    }
+
+    /// Compute GitHub URLs without opening a browser
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// let url = client
+    ///     .browse()
+    ///     .repo("cli/cli")
+    ///     .file_lines("README.md", 10, 20)
+    ///     .url();
+    /// ```
+    pub fn browse(&self) -> BrowseCommand {
+        BrowseCommand::new(self.executor.clone())
+    }
+
+    /// Access raw API commands
+    pub fn api(&self) -> ApiCommands {
+        ApiCommands::new(self.executor.clone())
+    }
 }
 
 /// Builder for GhClient using the Builder Pattern
 /// This allows for flexible configuration
 pub struct GhClientBuilder {
     gh_path: Option<String>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    env: Vec<(String, String)>,
+    cassette: Option<Cassette>,
+    timeout: Option<Duration>,
 }
 
 impl GhClientBuilder {
     /// Create a new builder
     pub fn new() -> Self {
-        Self { gh_path: None }
+        Self {
+            gh_path: None,
+            retry_policy: None,
+            rate_limiter: None,
+            env: Vec::new(),
+            cassette: None,
+            timeout: None,
+        }
     }
 
     /// Set a custom path to the gh binary
@@ -68,15 +219,67 @@ impl GhClientBuilder {
         self
     }
 
+    /// Retry transient failures (rate limits, 5xx, network errors) with backoff
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Bound concurrent and per-second gh processes across all clones of the client
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Set an environment variable for gh processes (e.g. GH_TOKEN, GH_HOST)
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Record invocations to, or replay them from, a cassette
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Kill gh processes that run longer than `timeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Build the GhClient
     pub fn build(self) -> GhClient {
-        let executor = if let Some(gh_path) = self.gh_path {
-            Arc::new(GhExecutor::new(gh_path))
+        let mut executor = if let Some(gh_path) = self.gh_path {
+            GhExecutor::new(gh_path)
         } else {
-            Arc::new(GhExecutor::default())
+            GhExecutor::default()
         };
 
-        GhClient { executor }
+        if let Some(policy) = self.retry_policy {
+            executor = executor.with_retry_policy(policy);
+        }
+
+        if let Some(limiter) = self.rate_limiter {
+            executor = executor.with_rate_limiter(limiter);
+        }
+
+        for (key, value) in self.env {
+            executor = executor.with_env(key, value);
+        }
+
+        if let Some(cassette) = self.cassette {
+            executor = executor.with_cassette(cassette);
+        }
+
+        if let Some(timeout) = self.timeout {
+            executor = executor.with_timeout(timeout);
+        }
+
+        GhClient {
+            executor: Arc::new(executor),
+        }
     }
 }
 
@@ -101,4 +304,23 @@ mod tests {
         let client = GhClient::builder().gh_path("/usr/local/bin/gh").build();
         assert!(Arc::strong_count(&client.executor) >= 1);
     }
+
+    #[test]
+    fn test_builder_retry_policy() {
+        let policy = RetryPolicy::new().max_attempts(5);
+        let client = GhClient::builder().retry_policy(policy.clone()).build();
+        assert_eq!(client.executor.retry_policy, policy);
+    }
+
+    #[test]
+    fn test_builder_rate_limiter_is_shared() {
+        let client = GhClient::builder()
+            .rate_limiter(RateLimiter::new().max_concurrent(2))
+            .build();
+        let clone = client.clone();
+        assert!(Arc::ptr_eq(
+            client.executor.limiter.as_ref().unwrap(),
+            clone.executor.limiter.as_ref().unwrap()
+        ));
+    }
 }
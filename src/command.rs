@@ -55,6 +55,27 @@ impl BaseCommand {
         self.args.extend(args.iter().map(|s| s.to_string()));
         self
     }
+
+    /// Values given for an option, in order
+    pub(crate) fn values(&self, key: &str) -> Vec<&str> {
+        self.args
+            .windows(2)
+            .filter(|pair| pair[0] == key)
+            .map(|pair| pair[1].as_str())
+            .collect()
+    }
+
+    /// Copy of the command without a flag (e.g. "--web")
+    pub(crate) fn without_flag(&self, flag: &str) -> Self {
+        Self {
+            args: self
+                .args
+                .iter()
+                .filter(|arg| *arg != flag)
+                .cloned()
+                .collect(),
+        }
+    }
 }
 
 impl CommandBuilder for BaseCommand {
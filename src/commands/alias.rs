@@ -0,0 +1,398 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::error::{GhError, Result};
+use crate::executor::GhExecutor;
+use std::sync::Arc;
+
+/// Alias commands namespace
+#[derive(Clone)]
+pub struct AliasCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl AliasCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List aliases
+    pub fn list(&self) -> AliasListCommand {
+        AliasListCommand::new(self.executor.clone())
+    }
+
+    /// Create an alias
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// client.alias().set("co", "pr checkout").clobber().execute();
+    /// client.alias().set("igrep", "gh issue list --label=\"$1\" | grep \"$2\"").shell().execute();
+    /// ```
+    pub fn set(&self, name: &str, expansion: &str) -> AliasSetCommand {
+        AliasSetCommand::new(self.executor.clone(), name, expansion)
+    }
+
+    /// Delete an alias
+    pub fn delete(&self, name: &str) -> AliasDeleteCommand {
+        AliasDeleteCommand::new(self.executor.clone(), Some(name))
+    }
+
+    /// Delete all aliases
+    pub fn delete_all(&self) -> AliasDeleteCommand {
+        AliasDeleteCommand::new(self.executor.clone(), None)
+    }
+
+    /// Import aliases from a YAML file or YAML content
+    pub fn import(&self) -> AliasImportCommand {
+        AliasImportCommand::new(self.executor.clone())
+    }
+}
+
+/// An alias returned by `AliasListCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alias {
+    pub name: String,
+    /// Expansion; shell aliases start with `!`
+    pub expansion: String,
+}
+
+impl Alias {
+    /// Whether the alias is run through `sh`
+    pub fn is_shell(&self) -> bool {
+        self.expansion.starts_with('!')
+    }
+
+    /// Parse a line of `gh alias list` output (`name: expansion`, or tab separated)
+    fn parse(line: &str) -> Option<Self> {
+        let (name, expansion) = line.split_once(": ").or_else(|| line.split_once('\t'))?;
+        let name = name.trim().trim_end_matches(':');
+        if name.is_empty() {
+            return None;
+        }
+        Some(Self {
+            name: name.to_string(),
+            expansion: unquote(expansion.trim()),
+        })
+    }
+}
+
+/// Parse `gh alias list` output, including multi-line `|` block values
+fn parse_aliases(output: &str) -> Result<Vec<Alias>> {
+    let unexpected =
+        |line: &str| GhError::ExecutionFailed(format!("unexpected alias list output: {}", line));
+    let mut aliases = Vec::new();
+    let mut lines = output.lines().peekable();
+    while let Some(line) = lines.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if line.starts_with(char::is_whitespace) {
+            return Err(unexpected(line));
+        }
+        let mut alias = Alias::parse(line).ok_or_else(|| unexpected(line))?;
+        let chomp = match alias.expansion.as_str() {
+            "|" => "\n",
+            "|-" => "",
+            _ => {
+                aliases.push(alias);
+                continue;
+            }
+        };
+
+        // The block is every following blank or indented line
+        let mut block = Vec::new();
+        while let Some(next) = lines.peek() {
+            if !next.trim().is_empty() && !next.starts_with(char::is_whitespace) {
+                break;
+            }
+            block.push(*next);
+            lines.next();
+        }
+        while block.last().map_or(false, |l| l.trim().is_empty()) {
+            block.pop();
+        }
+        let indent = block
+            .iter()
+            .find(|l| !l.trim().is_empty())
+            .map_or(0, |l| l.len() - l.trim_start().len());
+        let body: Vec<&str> = block
+            .iter()
+            .map(|l| l.get(indent..).unwrap_or(""))
+            .collect();
+        alias.expansion = format!("{}{}", body.join("\n"), chomp);
+        aliases.push(alias);
+    }
+    Ok(aliases)
+}
+
+/// Remove YAML quoting from a scalar
+fn unquote(value: &str) -> String {
+    if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        value[1..value.len() - 1].replace("''", "'")
+    } else if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        value[1..value.len() - 1]
+            .replace("\\\"", "\"")
+            .replace("\\\\", "\\")
+    } else {
+        value.to_string()
+    }
+}
+
+/// Command for listing aliases
+pub struct AliasListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl AliasListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["alias", "list"]),
+        }
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the aliases
+    pub fn fetch(&self) -> Result<Vec<Alias>> {
+        parse_aliases(&self.execute()?)
+    }
+}
+
+impl GhCommand for AliasListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for creating an alias
+pub struct AliasSetCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl AliasSetCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str, expansion: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["alias", "set"]).args(&[name, expansion]),
+        }
+    }
+
+    /// Run the expansion through `sh`
+    pub fn shell(mut self) -> Self {
+        self.cmd = self.cmd.flag("--shell");
+        self
+    }
+
+    /// Overwrite an existing alias of the same name
+    pub fn clobber(mut self) -> Self {
+        self.cmd = self.cmd.flag("--clobber");
+        self
+    }
+
+    /// Execute the set command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for AliasSetCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for deleting aliases
+pub struct AliasDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl AliasDeleteCommand {
+    /// Delete the named alias, or all aliases for `None`
+    fn new(executor: Arc<GhExecutor>, name: Option<&str>) -> Self {
+        let cmd = BaseCommand::with_subcommands(&["alias", "delete"]);
+        Self {
+            executor,
+            cmd: match name {
+                Some(name) => cmd.arg(name),
+                None => cmd.flag("--all"),
+            },
+        }
+    }
+
+    /// Execute the delete command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for AliasDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for importing aliases from YAML
+///
+/// Aliases are read from `.file()` or, with `.yaml()`, passed via stdin.
+pub struct AliasImportCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    file: Option<String>,
+    yaml: Option<String>,
+}
+
+impl AliasImportCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["alias", "import"]),
+            file: None,
+            yaml: None,
+        }
+    }
+
+    /// Import from a YAML file
+    pub fn file(mut self, path: &str) -> Self {
+        self.file = Some(path.to_string());
+        self
+    }
+
+    /// Import from YAML content (`name: expansion` per line)
+    pub fn yaml(mut self, yaml: &str) -> Self {
+        self.yaml = Some(yaml.to_string());
+        self
+    }
+
+    /// Overwrite existing aliases of the same name
+    pub fn clobber(mut self) -> Self {
+        self.cmd = self.cmd.flag("--clobber");
+        self
+    }
+
+    /// Execute the import command
+    pub fn execute(&self) -> Result<String> {
+        match (&self.yaml, &self.file) {
+            (Some(yaml), None) => self.executor.execute_with_input(&self.build_args(), yaml),
+            (None, Some(_)) => GhCommand::execute(self, self.executor.as_ref()),
+            _ => Err(GhError::InvalidCommand(
+                "alias import requires exactly one of file or yaml".to_string(),
+            )),
+        }
+    }
+}
+
+impl GhCommand for AliasImportCommand {
+    fn build_args(&self) -> Vec<String> {
+        let mut args = self.cmd.build_args();
+        match (&self.yaml, &self.file) {
+            (Some(_), _) => args.push("-".to_string()),
+            (None, Some(path)) => args.push(path.clone()),
+            (None, None) => {}
+        }
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_alias_parsing() {
+        let alias = Alias::parse("co: pr checkout").unwrap();
+        assert_eq!(alias.name, "co");
+        assert_eq!(alias.expansion, "pr checkout");
+        assert!(!alias.is_shell());
+
+        let alias = Alias::parse("igrep: '!gh issue list | grep ''bug'''").unwrap();
+        assert_eq!(alias.expansion, "!gh issue list | grep 'bug'");
+        assert!(alias.is_shell());
+
+        let alias = Alias::parse("co:\tpr checkout").unwrap();
+        assert_eq!(alias.name, "co");
+        assert_eq!(alias.expansion, "pr checkout");
+        assert!(Alias::parse("garbage").is_none());
+
+        let alias = Alias::parse("tabs: !printf 'a\tb'").unwrap();
+        assert_eq!(alias.name, "tabs");
+        assert_eq!(alias.expansion, "!printf 'a\tb'");
+    }
+
+    #[test]
+    fn test_alias_list_parsing() {
+        let output = "co: pr checkout\n\
+                      triage: |-\n    !gh issue list --label triage\n\n      | head -n 5\n\
+                      notes: |\n  !cat notes.md\n\n\
+                      v: pr view\n";
+        let aliases = parse_aliases(output).unwrap();
+        let parsed: Vec<(&str, &str)> = aliases
+            .iter()
+            .map(|a| (a.name.as_str(), a.expansion.as_str()))
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![
+                ("co", "pr checkout"),
+                ("triage", "!gh issue list --label triage\n\n  | head -n 5"),
+                ("notes", "!cat notes.md\n"),
+                ("v", "pr view"),
+            ]
+        );
+
+        assert!(parse_aliases("  stray: indent\n").is_err());
+    }
+
+    #[test]
+    fn test_import_args() {
+        let executor = Arc::new(GhExecutor::default());
+        let import = AliasImportCommand::new(executor.clone())
+            .file("aliases.yml")
+            .clobber();
+        assert_eq!(
+            import.build_args(),
+            vec!["alias", "import", "--clobber", "aliases.yml"]
+        );
+
+        let import = AliasImportCommand::new(executor).yaml("co: pr checkout\n");
+        assert_eq!(import.build_args(), vec!["alias", "import", "-"]);
+    }
+
+    #[test]
+    fn test_import_yaml_is_written_to_stdin() {
+        let executor =
+            GhExecutor::default().with_cassette(Cassette::from_interactions(vec![Interaction {
+                args: vec!["alias".to_string(), "import".to_string(), "-".to_string()],
+                env: BTreeMap::new(),
+                stdin: Some("co: pr checkout\n".to_string()),
+                stdout: String::new(),
+                stderr: String::new(),
+                exit_code: 0,
+            }]));
+
+        AliasImportCommand::new(Arc::new(executor))
+            .yaml("co: pr checkout\n")
+            .execute()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_import_requires_exactly_one_source() {
+        let executor = Arc::new(GhExecutor::default());
+        for import in [
+            AliasImportCommand::new(executor.clone()),
+            AliasImportCommand::new(executor)
+                .file("aliases.yml")
+                .yaml("co: pr checkout"),
+        ] {
+            assert!(matches!(import.execute(), Err(GhError::InvalidCommand(_))));
+        }
+    }
+}
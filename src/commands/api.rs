@@ -0,0 +1,121 @@
+use crate::command::{BaseCommand, GhCommand};
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// API commands namespace
+#[derive(Clone)]
+pub struct ApiCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl ApiCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// Query the remaining REST and GraphQL rate limit budget
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// let limits = client.api().rate_limit().fetch().unwrap();
+    /// println!("{} REST calls left", limits.core.remaining);
+    /// ```
+    pub fn rate_limit(&self) -> ApiRateLimitCommand {
+        ApiRateLimitCommand::new(self.executor.clone())
+    }
+}
+
+/// Arguments for a paginated REST listing (`gh api --paginate path?query`)
+pub(crate) fn paginated(path: &str, query: &[(&str, &str)]) -> Vec<String> {
+    let params: Vec<String> = std::iter::once(("per_page", "100"))
+        .chain(query.iter().copied())
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+    vec![
+        "api".to_string(),
+        "--paginate".to_string(),
+        format!("{}?{}", path, params.join("&")),
+    ]
+}
+
+/// Percent-encode all but unreserved characters, for URL path segments and query values
+pub(crate) fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Budget for a single rate limit resource
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RateLimitResource {
+    pub limit: u64,
+    pub used: u64,
+    pub remaining: u64,
+    /// Unix timestamp (seconds) when the budget resets
+    pub reset: u64,
+}
+
+impl RateLimitResource {
+    /// Time at which the budget resets
+    pub fn reset_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.reset)
+    }
+}
+
+/// Rate limit budgets reported by `gh api rate_limit`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RateLimit {
+    /// REST API budget
+    pub core: RateLimitResource,
+    /// GraphQL API budget
+    pub graphql: RateLimitResource,
+    /// Search API budget
+    pub search: RateLimitResource,
+}
+
+#[derive(Deserialize)]
+struct RateLimitResponse {
+    resources: RateLimit,
+}
+
+/// Command for querying the API rate limit
+pub struct ApiRateLimitCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ApiRateLimitCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["api", "rate_limit"]),
+        }
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the rate limit budgets
+    pub fn fetch(&self) -> Result<RateLimit> {
+        let response: RateLimitResponse = self.executor.execute_json(&self.build_args())?;
+        Ok(response.resources)
+    }
+}
+
+impl GhCommand for ApiRateLimitCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
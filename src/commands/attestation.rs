@@ -0,0 +1,333 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::error::{GhError, Result};
+use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Artifact attestation commands namespace
+#[derive(Clone)]
+pub struct AttestationCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl AttestationCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// Verify an artifact (file path or `oci://<image-uri>`)
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// let results = client
+    ///     .attestation()
+    ///     .verify("dist/app.tar.gz")
+    ///     .repo("octo/app")
+    ///     .signer_workflow("octo/app/.github/workflows/release.yml")
+    ///     .fetch();
+    /// ```
+    pub fn verify(&self, artifact: &str) -> AttestationVerifyCommand {
+        AttestationVerifyCommand::new(self.executor.clone(), artifact)
+    }
+
+    /// Download the attestations of an artifact to a `<digest>.jsonl` bundle
+    pub fn download(&self, artifact: &str) -> AttestationDownloadCommand {
+        AttestationDownloadCommand::new(self.executor.clone(), artifact)
+    }
+}
+
+/// Require exactly one of `--owner` and `--repo`, as gh does
+fn check_owner_or_repo(command: &str, owner: bool, repo: bool) -> Result<()> {
+    if owner == repo {
+        return Err(GhError::InvalidCommand(format!(
+            "attestation {} requires exactly one of owner or repo",
+            command
+        )));
+    }
+    Ok(())
+}
+
+/// Subject of an attestation statement
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AttestationSubject {
+    pub name: String,
+    /// Digests keyed by algorithm (e.g. `sha256`)
+    #[serde(default)]
+    pub digest: BTreeMap<String, String>,
+}
+
+/// In-toto statement that was verified
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttestationStatement {
+    #[serde(default)]
+    pub subject: Vec<AttestationSubject>,
+    /// e.g. `https://slsa.dev/provenance/v1`
+    pub predicate_type: String,
+    #[serde(default)]
+    pub predicate: serde_json::Value,
+}
+
+/// Claims of the signing certificate
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct SigningCertificate {
+    #[serde(default, rename = "certificateIssuer")]
+    pub certificate_issuer: Option<String>,
+    #[serde(default, rename = "subjectAlternativeName")]
+    pub subject_alternative_name: Option<String>,
+    #[serde(default)]
+    pub issuer: Option<String>,
+    /// Workflow that signed the attestation
+    #[serde(default, rename = "buildSignerURI")]
+    pub build_signer_uri: Option<String>,
+    #[serde(default, rename = "buildSignerDigest")]
+    pub build_signer_digest: Option<String>,
+    /// `github-hosted` or `self-hosted`
+    #[serde(default, rename = "runnerEnvironment")]
+    pub runner_environment: Option<String>,
+    #[serde(default, rename = "sourceRepositoryURI")]
+    pub source_repository_uri: Option<String>,
+    #[serde(default, rename = "sourceRepositoryRef")]
+    pub source_repository_ref: Option<String>,
+    #[serde(default, rename = "sourceRepositoryDigest")]
+    pub source_repository_digest: Option<String>,
+}
+
+/// Signature information of a verified attestation
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct AttestationSignature {
+    #[serde(default)]
+    pub certificate: Option<SigningCertificate>,
+}
+
+/// Timestamp proving when an attestation was signed
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct VerifiedTimestamp {
+    /// `Tlog` or `TimestampAuthority`
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub uri: String,
+    pub timestamp: Timestamp,
+}
+
+/// Result of verifying one attestation
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationResult {
+    #[serde(default)]
+    pub media_type: String,
+    pub statement: AttestationStatement,
+    #[serde(default)]
+    pub signature: AttestationSignature,
+    #[serde(default)]
+    pub verified_timestamps: Vec<VerifiedTimestamp>,
+}
+
+/// A verified attestation returned by `AttestationVerifyCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttestationVerification {
+    pub verification_result: VerificationResult,
+    /// The raw Sigstore bundle
+    #[serde(default)]
+    pub attestation: serde_json::Value,
+}
+
+/// Command for verifying an artifact's attestations
+///
+/// Verification failure makes gh exit non-zero, surfacing as
+/// `GhError::CommandFailed`.
+pub struct AttestationVerifyCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    owner: bool,
+    repo: bool,
+}
+
+impl AttestationVerifyCommand {
+    fn new(executor: Arc<GhExecutor>, artifact: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["attestation", "verify"]).arg(artifact),
+            owner: false,
+            repo: false,
+        }
+    }
+
+    /// Require attestations from repositories of this owner
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = self.cmd.option("--owner", owner);
+        self.owner = true;
+        self
+    }
+
+    /// Require attestations from this repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self.repo = true;
+        self
+    }
+
+    /// Require the attestation to be signed by this workflow (`owner/repo/path/to/workflow.yml`)
+    pub fn signer_workflow(mut self, workflow: &str) -> Self {
+        self.cmd = self.cmd.option("--signer-workflow", workflow);
+        self
+    }
+
+    /// Require the attestation to be signed by a workflow in this repository
+    pub fn signer_repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--signer-repo", repo);
+        self
+    }
+
+    /// Only verify attestations with this predicate type (gh defaults to SLSA provenance v1)
+    pub fn predicate_type(mut self, predicate_type: &str) -> Self {
+        self.cmd = self.cmd.option("--predicate-type", predicate_type);
+        self
+    }
+
+    /// Verify against a local bundle instead of fetching attestations from GitHub
+    pub fn bundle(mut self, path: &str) -> Self {
+        self.cmd = self.cmd.option("--bundle", path);
+        self
+    }
+
+    /// Trusted root file, for fully offline verification with `.bundle()`
+    pub fn custom_trusted_root(mut self, path: &str) -> Self {
+        self.cmd = self.cmd.option("--custom-trusted-root", path);
+        self
+    }
+
+    /// Fail if the attestation was produced on a self-hosted runner
+    pub fn deny_self_hosted_runners(mut self) -> Self {
+        self.cmd = self.cmd.flag("--deny-self-hosted-runners");
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        check_owner_or_repo("verify", self.owner, self.repo)?;
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the verified attestations
+    pub fn fetch(&self) -> Result<Vec<AttestationVerification>> {
+        check_owner_or_repo("verify", self.owner, self.repo)?;
+        let cmd = self.cmd.clone().option("--format", "json");
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for AttestationVerifyCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for downloading an artifact's attestations
+pub struct AttestationDownloadCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    owner: bool,
+    repo: bool,
+}
+
+impl AttestationDownloadCommand {
+    fn new(executor: Arc<GhExecutor>, artifact: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["attestation", "download"]).arg(artifact),
+            owner: false,
+            repo: false,
+        }
+    }
+
+    /// Download attestations from repositories of this owner
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = self.cmd.option("--owner", owner);
+        self.owner = true;
+        self
+    }
+
+    /// Download attestations from this repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self.repo = true;
+        self
+    }
+
+    /// Only download attestations with this predicate type
+    pub fn predicate_type(mut self, predicate_type: &str) -> Self {
+        self.cmd = self.cmd.option("--predicate-type", predicate_type);
+        self
+    }
+
+    /// Limit the number of attestations
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute the download command
+    pub fn execute(&self) -> Result<String> {
+        check_owner_or_repo("download", self.owner, self.repo)?;
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for AttestationDownloadCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_owner_or_repo_required() {
+        let executor = Arc::new(GhExecutor::default());
+        let verify = AttestationVerifyCommand::new(executor.clone(), "app.tar.gz");
+        assert!(matches!(verify.fetch(), Err(GhError::InvalidCommand(_))));
+        let verify = AttestationVerifyCommand::new(executor, "app.tar.gz")
+            .owner("octo")
+            .repo("octo/app");
+        assert!(matches!(verify.execute(), Err(GhError::InvalidCommand(_))));
+    }
+
+    #[test]
+    fn test_verification_decoding() {
+        let json = r#"[{
+            "attestation": {"bundle": {}},
+            "verificationResult": {
+                "mediaType": "application/vnd.dev.sigstore.verificationresult+json;version=0.1",
+                "signature": {"certificate": {
+                    "buildSignerURI": "https://github.com/octo/app/.github/workflows/release.yml@refs/tags/v1.0.0",
+                    "runnerEnvironment": "github-hosted"
+                }},
+                "statement": {
+                    "_type": "https://in-toto.io/Statement/v1",
+                    "subject": [{"name": "app.tar.gz", "digest": {"sha256": "abc123"}}],
+                    "predicateType": "https://slsa.dev/provenance/v1",
+                    "predicate": {"buildDefinition": {}}
+                },
+                "verifiedTimestamps": [{"type": "Tlog", "uri": "https://rekor.sigstore.dev", "timestamp": "2024-05-01T10:00:00Z"}]
+            }
+        }]"#;
+        let results: Vec<AttestationVerification> = serde_json::from_str(json).unwrap();
+        let result = &results[0].verification_result;
+        assert_eq!(
+            result.statement.predicate_type,
+            "https://slsa.dev/provenance/v1"
+        );
+        assert_eq!(result.statement.subject[0].digest["sha256"], "abc123");
+        let cert = result.signature.certificate.as_ref().unwrap();
+        assert_eq!(cert.runner_environment.as_deref(), Some("github-hosted"));
+        assert_eq!(result.verified_timestamps[0].kind, "Tlog");
+    }
+}
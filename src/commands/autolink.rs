@@ -0,0 +1,286 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Autolink commands, accessed through `RepoCommands::autolink`
+#[derive(Clone)]
+pub struct AutolinkCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl AutolinkCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List autolink references
+    pub fn list(&self) -> AutolinkListCommand {
+        AutolinkListCommand::new(self.executor.clone())
+    }
+
+    /// Create an autolink reference
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// client
+    ///     .repo()
+    ///     .autolink()
+    ///     .create("JIRA-", "https://jira.example.com/browse/JIRA-<num>")
+    ///     .numeric()
+    ///     .repo("octo/app")
+    ///     .execute();
+    /// ```
+    pub fn create(&self, key_prefix: &str, url_template: &str) -> AutolinkCreateCommand {
+        AutolinkCreateCommand::new(self.executor.clone(), key_prefix, url_template)
+    }
+
+    /// View an autolink reference by ID
+    pub fn view(&self, id: u64) -> AutolinkViewCommand {
+        AutolinkViewCommand::new(self.executor.clone(), id)
+    }
+
+    /// Delete an autolink reference by ID
+    pub fn delete(&self, id: u64) -> AutolinkDeleteCommand {
+        AutolinkDeleteCommand::new(self.executor.clone(), id)
+    }
+}
+
+const AUTOLINK_FIELDS: &str = "id,keyPrefix,urlTemplate,isAlphanumeric";
+
+/// An autolink reference
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Autolink {
+    pub id: u64,
+    pub key_prefix: String,
+    /// URL with a `<num>` placeholder for the reference
+    pub url_template: String,
+    pub is_alphanumeric: bool,
+}
+
+/// Command for listing autolink references
+pub struct AutolinkListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl AutolinkListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["repo", "autolink", "list"]),
+        }
+    }
+
+    /// List autolinks of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the autolinks
+    pub fn fetch(&self) -> Result<Vec<Autolink>> {
+        let cmd = self.cmd.clone().option("--json", AUTOLINK_FIELDS);
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for AutolinkListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for creating an autolink reference
+pub struct AutolinkCreateCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl AutolinkCreateCommand {
+    fn new(executor: Arc<GhExecutor>, key_prefix: &str, url_template: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["repo", "autolink", "create"])
+                .args(&[key_prefix, url_template]),
+        }
+    }
+
+    /// Only match numeric references (alphanumeric by default)
+    pub fn numeric(mut self) -> Self {
+        self.cmd = self.cmd.flag("--numeric");
+        self
+    }
+
+    /// Create the autolink in the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute the create command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for AutolinkCreateCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for viewing an autolink reference
+pub struct AutolinkViewCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl AutolinkViewCommand {
+    fn new(executor: Arc<GhExecutor>, id: u64) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["repo", "autolink", "view"]).arg(&id.to_string()),
+        }
+    }
+
+    /// View an autolink of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the autolink
+    pub fn fetch(&self) -> Result<Autolink> {
+        let cmd = self.cmd.clone().option("--json", AUTOLINK_FIELDS);
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for AutolinkViewCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for deleting an autolink reference
+pub struct AutolinkDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl AutolinkDeleteCommand {
+    fn new(executor: Arc<GhExecutor>, id: u64) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["repo", "autolink", "delete"])
+                .arg(&id.to_string()),
+        }
+    }
+
+    /// Confirm deletion without prompting (required when not interactive)
+    pub fn yes(mut self) -> Self {
+        self.cmd = self.cmd.flag("--yes");
+        self
+    }
+
+    /// Delete an autolink of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute the delete command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for AutolinkDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_args() {
+        let executor = Arc::new(GhExecutor::default());
+        let create = AutolinkCreateCommand::new(
+            executor.clone(),
+            "JIRA-",
+            "https://jira.example.com/browse/JIRA-<num>",
+        )
+        .numeric()
+        .repo("octo/app");
+        assert_eq!(
+            create.build_args(),
+            vec![
+                "repo",
+                "autolink",
+                "create",
+                "JIRA-",
+                "https://jira.example.com/browse/JIRA-<num>",
+                "--numeric",
+                "--repo",
+                "octo/app"
+            ]
+        );
+
+        let delete = AutolinkDeleteCommand::new(executor, 7).yes();
+        assert_eq!(
+            delete.build_args(),
+            vec!["repo", "autolink", "delete", "7", "--yes"]
+        );
+    }
+
+    #[test]
+    fn test_list_fetch() {
+        let executor = GhExecutor::default().with_cassette(Cassette::from_interactions(vec![
+            Interaction {
+                args: [
+                    "repo",
+                    "autolink",
+                    "list",
+                    "--repo",
+                    "octo/app",
+                    "--json",
+                    AUTOLINK_FIELDS,
+                ]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+                env: BTreeMap::new(),
+                stdin: None,
+                stdout: r#"[{"id": 1, "keyPrefix": "TICKET-", "urlTemplate": "https://t.example/<num>", "isAlphanumeric": false}]"#.to_string(),
+                stderr: String::new(),
+                exit_code: 0,
+            },
+        ]));
+
+        let autolinks = AutolinkListCommand::new(Arc::new(executor))
+            .repo("octo/app")
+            .fetch()
+            .unwrap();
+        assert_eq!(autolinks[0].key_prefix, "TICKET-");
+        assert!(!autolinks[0].is_alphanumeric);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::api::percent_encode;
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use std::sync::Arc;
+
+/// Command for computing GitHub URLs with `gh browse --no-browser`
+///
+/// Without a target the URL of the repository home page is returned.
+pub struct BrowseCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl BrowseCommand {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::new("browse").flag("--no-browser"),
+        }
+    }
+
+    /// Repository to browse (`owner/name`); defaults to the current directory
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// An issue or pull request by number
+    pub fn number(mut self, number: u32) -> Self {
+        self.cmd = self.cmd.arg(&number.to_string());
+        self
+    }
+
+    /// A file or directory
+    pub fn file(mut self, path: &str) -> Self {
+        self.cmd = self.cmd.arg(path);
+        self
+    }
+
+    /// A range of lines in a file; a single line when `start == end`
+    pub fn file_lines(mut self, path: &str, start: u32, end: u32) -> Self {
+        let target = if start == end {
+            format!("{}:{}", path, start)
+        } else {
+            format!("{}:{}-{}", path, start, end)
+        };
+        self.cmd = self.cmd.arg(&target);
+        self
+    }
+
+    /// A commit, or files at that commit when combined with `.file()`
+    pub fn commit(mut self, sha: &str) -> Self {
+        self.cmd = self.cmd.arg(&format!("--commit={}", sha));
+        self
+    }
+
+    /// A branch, or files on that branch when combined with `.file()`
+    pub fn branch(mut self, branch: &str) -> Self {
+        self.cmd = self.cmd.option("--branch", branch);
+        self
+    }
+
+    /// The repository settings page
+    pub fn settings(mut self) -> Self {
+        self.cmd = self.cmd.flag("--settings");
+        self
+    }
+
+    /// The repository projects page
+    pub fn projects(mut self) -> Self {
+        self.cmd = self.cmd.flag("--projects");
+        self
+    }
+
+    /// The repository wiki
+    pub fn wiki(mut self) -> Self {
+        self.cmd = self.cmd.flag("--wiki");
+        self
+    }
+
+    /// The repository releases page
+    pub fn releases(mut self) -> Self {
+        self.cmd = self.cmd.flag("--releases");
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and get the URL
+    pub fn url(&self) -> Result<String> {
+        Ok(self.execute()?.trim().to_string())
+    }
+}
+
+impl GhCommand for BrowseCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Web URL of the current directory's repository
+pub(crate) fn repo_url(executor: &GhExecutor) -> Result<String> {
+    let cmd = BaseCommand::with_subcommands(&["repo", "view"])
+        .option("--json", "url")
+        .option("--jq", ".url");
+    Ok(GhCommand::execute(&cmd, executor)?.trim().to_string())
+}
+
+/// Query string (`?key=value&...`) of the non-empty parameters
+pub(crate) fn query_string(params: &[(&str, String)]) -> String {
+    let pairs: Vec<String> = params
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| format!("{}={}", key, percent_encode(value)))
+        .collect();
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", pairs.join("&"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_browse_args() {
+        let executor = Arc::new(GhExecutor::default());
+        let browse = BrowseCommand::new(executor.clone())
+            .repo("cli/cli")
+            .branch("trunk")
+            .file_lines("main.go", 10, 20);
+        assert_eq!(
+            browse.build_args(),
+            vec![
+                "browse",
+                "--no-browser",
+                "--repo",
+                "cli/cli",
+                "--branch",
+                "trunk",
+                "main.go:10-20"
+            ]
+        );
+
+        let browse = BrowseCommand::new(executor)
+            .commit("77507cd")
+            .file_lines("go.mod", 3, 3);
+        assert_eq!(
+            browse.build_args(),
+            vec!["browse", "--no-browser", "--commit=77507cd", "go.mod:3"]
+        );
+    }
+
+    #[test]
+    fn test_query_string() {
+        assert_eq!(
+            query_string(&[
+                ("title", "Fix: a & b".to_string()),
+                ("body", String::new()),
+                ("labels", "bug,p1".to_string()),
+            ]),
+            "?title=Fix%3A%20a%20%26%20b&labels=bug%2Cp1"
+        );
+        assert_eq!(query_string(&[("title", String::new())]), "");
+    }
+}
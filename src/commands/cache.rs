@@ -0,0 +1,485 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::search::SortOrder;
+use crate::error::{GhError, Result};
+use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Actions cache commands namespace
+#[derive(Clone)]
+pub struct CacheCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl CacheCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List Actions caches
+    pub fn list(&self) -> CacheListCommand {
+        CacheListCommand::new(self.executor.clone())
+    }
+
+    /// Delete a cache by ID or key
+    pub fn delete(&self, id_or_key: &str) -> CacheDeleteCommand {
+        CacheDeleteCommand::new(self.executor.clone(), Some(id_or_key))
+    }
+
+    /// Delete all caches
+    pub fn delete_all(&self) -> CacheDeleteCommand {
+        CacheDeleteCommand::new(self.executor.clone(), None)
+    }
+
+    /// Delete caches that are too old or do not fit a size budget
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// # use std::time::Duration;
+    /// let client = GhClient::new();
+    /// let report = client
+    ///     .cache()
+    ///     .prune()
+    ///     .repo("octo/monorepo")
+    ///     .older_than(Duration::from_secs(7 * 24 * 3600))
+    ///     .max_total_size(8 * 1024 * 1024 * 1024)
+    ///     .execute();
+    /// ```
+    pub fn prune(&self) -> CachePruneCommand {
+        CachePruneCommand::new(self.executor.clone())
+    }
+}
+
+/// Sort field for cache listings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheSort {
+    CreatedAt,
+    LastAccessedAt,
+    SizeInBytes,
+}
+
+impl CacheSort {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CacheSort::CreatedAt => "created_at",
+            CacheSort::LastAccessedAt => "last_accessed_at",
+            CacheSort::SizeInBytes => "size_in_bytes",
+        }
+    }
+}
+
+const CACHE_FIELDS: &[&str] = &[
+    "id",
+    "key",
+    "ref",
+    "sizeInBytes",
+    "createdAt",
+    "lastAccessedAt",
+    "version",
+];
+
+/// An Actions cache entry
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheEntry {
+    pub id: u64,
+    pub key: String,
+    /// Git ref the cache was created for (e.g. `refs/heads/main`)
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub size_in_bytes: u64,
+    pub created_at: Timestamp,
+    pub last_accessed_at: Timestamp,
+    #[serde(default)]
+    pub version: String,
+}
+
+/// Command for listing caches
+pub struct CacheListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CacheListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["cache", "list"]),
+        }
+    }
+
+    /// List caches of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Only caches whose key starts with the prefix
+    pub fn key_prefix(mut self, prefix: &str) -> Self {
+        self.cmd = self.cmd.option("--key", prefix);
+        self
+    }
+
+    /// Only caches of the given ref (e.g. `refs/heads/main`)
+    pub fn git_ref(mut self, git_ref: &str) -> Self {
+        self.cmd = self.cmd.option("--ref", git_ref);
+        self
+    }
+
+    /// Sort by the given field
+    pub fn sort(mut self, sort: CacheSort) -> Self {
+        self.cmd = self.cmd.option("--sort", sort.as_str());
+        self
+    }
+
+    /// Sort direction
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.cmd = self.cmd.option("--order", order.as_str());
+        self
+    }
+
+    /// Limit the number of results (gh defaults to 30)
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the caches
+    pub fn fetch(&self) -> Result<Vec<CacheEntry>> {
+        let cmd = self.cmd.clone().option("--json", &CACHE_FIELDS.join(","));
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for CacheListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for deleting caches
+pub struct CacheDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CacheDeleteCommand {
+    /// Delete the cache with the given ID or key, or all caches for `None`
+    fn new(executor: Arc<GhExecutor>, id_or_key: Option<&str>) -> Self {
+        let cmd = BaseCommand::with_subcommands(&["cache", "delete"]);
+        Self {
+            executor,
+            cmd: match id_or_key {
+                Some(id_or_key) => cmd.arg(id_or_key),
+                None => cmd.flag("--all"),
+            },
+        }
+    }
+
+    /// Delete caches of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Succeed when there are no caches to delete
+    pub fn succeed_on_no_caches(mut self) -> Self {
+        self.cmd = self.cmd.flag("--succeed-on-no-caches");
+        self
+    }
+
+    /// Execute the delete command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for CacheDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Pick the caches to delete: first everything not accessed within `max_age`,
+/// then the least recently accessed until the rest fits in `max_total_size`
+fn select_for_prune(
+    caches: &[CacheEntry],
+    now: SystemTime,
+    max_age: Option<Duration>,
+    max_total_size: Option<u64>,
+) -> Vec<CacheEntry> {
+    let mut kept: Vec<&CacheEntry> = caches.iter().collect();
+    let mut pruned = Vec::new();
+
+    if let Some(max_age) = max_age {
+        let cutoff = now
+            .checked_sub(max_age)
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(i64::MIN, |d| d.as_secs() as i64);
+        let (old, recent) = kept.into_iter().partition(|c| {
+            c.last_accessed_at
+                .unix_seconds()
+                .map_or(false, |t| t < cutoff)
+        });
+        pruned.extend(old);
+        kept = recent;
+    }
+
+    if let Some(budget) = max_total_size {
+        kept.sort_by_key(|c| c.last_accessed_at.unix_seconds().unwrap_or(i64::MIN));
+        let mut total: u64 = kept.iter().map(|c| c.size_in_bytes).sum();
+        for cache in kept {
+            if total <= budget {
+                break;
+            }
+            total -= cache.size_in_bytes;
+            pruned.push(cache);
+        }
+    }
+
+    pruned.into_iter().cloned().collect()
+}
+
+/// Most caches `CachePruneCommand` reads from a repository
+const PRUNE_LIST_LIMIT: u32 = 10_000;
+
+/// Outcome of `CachePruneCommand::execute`
+#[derive(Debug, Default)]
+pub struct CachePruneReport {
+    /// Caches that were deleted
+    pub deleted: Vec<CacheEntry>,
+    /// Caches whose deletion failed, with the error
+    pub failed: Vec<(CacheEntry, GhError)>,
+}
+
+impl CachePruneReport {
+    /// The deleted caches, or the first error if any deletion failed
+    pub fn into_result(self) -> Result<Vec<CacheEntry>> {
+        match self.failed.into_iter().next() {
+            Some((_, error)) => Err(error),
+            None => Ok(self.deleted),
+        }
+    }
+}
+
+/// Command for pruning caches by age and total size
+pub struct CachePruneCommand {
+    executor: Arc<GhExecutor>,
+    repo: Option<String>,
+    key_prefix: Option<String>,
+    max_age: Option<Duration>,
+    max_total_size: Option<u64>,
+}
+
+impl CachePruneCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            repo: None,
+            key_prefix: None,
+            max_age: None,
+            max_total_size: None,
+        }
+    }
+
+    /// Prune caches of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.repo = Some(repo.to_string());
+        self
+    }
+
+    /// Only consider caches whose key starts with the prefix
+    pub fn key_prefix(mut self, prefix: &str) -> Self {
+        self.key_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Delete caches not accessed within the given age
+    pub fn older_than(mut self, age: Duration) -> Self {
+        self.max_age = Some(age);
+        self
+    }
+
+    /// Delete the least recently used caches until the total size fits the budget
+    pub fn max_total_size(mut self, bytes: u64) -> Self {
+        self.max_total_size = Some(bytes);
+        self
+    }
+
+    /// Compute the caches to delete without deleting them
+    pub fn plan(&self) -> Result<Vec<CacheEntry>> {
+        // gh pages through the API up to the limit
+        let mut list = CacheListCommand::new(self.executor.clone()).limit(PRUNE_LIST_LIMIT);
+        if let Some(repo) = &self.repo {
+            list = list.repo(repo);
+        }
+        if let Some(prefix) = &self.key_prefix {
+            list = list.key_prefix(prefix);
+        }
+        let caches = list.fetch()?;
+        if caches.len() >= PRUNE_LIST_LIMIT as usize {
+            return Err(GhError::InvalidCommand(format!(
+                "cannot prune repositories with {} or more caches",
+                PRUNE_LIST_LIMIT
+            )));
+        }
+        Ok(select_for_prune(
+            &caches,
+            SystemTime::now(),
+            self.max_age,
+            self.max_total_size,
+        ))
+    }
+
+    /// Delete the selected caches, reporting which were deleted and which failed
+    ///
+    /// A failed deletion doesn't stop the others. Only planning errors (the
+    /// listing) are returned as `Err`.
+    pub fn execute(&self) -> Result<CachePruneReport> {
+        let mut report = CachePruneReport::default();
+        for cache in self.plan()? {
+            let mut cmd =
+                CacheDeleteCommand::new(self.executor.clone(), Some(&cache.id.to_string()));
+            if let Some(repo) = &self.repo {
+                cmd = cmd.repo(repo);
+            }
+            match cmd.execute() {
+                Ok(_) => report.deleted.push(cache),
+                Err(error) => report.failed.push((cache, error)),
+            }
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    fn cache(id: u64, size: u64, last_accessed: &str) -> CacheEntry {
+        CacheEntry {
+            id,
+            key: format!("key-{}", id),
+            git_ref: "refs/heads/main".to_string(),
+            size_in_bytes: size,
+            created_at: Timestamp::new("2024-01-01T00:00:00Z"),
+            last_accessed_at: Timestamp::new(last_accessed),
+            version: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_select_for_prune() {
+        let now = Timestamp::new("2024-01-10T00:00:00Z")
+            .to_system_time()
+            .unwrap();
+        let caches = vec![
+            cache(1, 100, "2024-01-01T00:00:00Z"),
+            cache(2, 300, "2024-01-08T00:00:00Z"),
+            cache(3, 200, "2024-01-09T00:00:00Z"),
+            cache(4, 50, "2024-01-07T00:00:00Z"),
+        ];
+        let week = Duration::from_secs(7 * 24 * 3600);
+        let ids = |pruned: Vec<CacheEntry>| pruned.iter().map(|c| c.id).collect::<Vec<_>>();
+
+        assert_eq!(
+            ids(select_for_prune(&caches, now, Some(week), None)),
+            vec![1]
+        );
+        assert_eq!(
+            ids(select_for_prune(&caches, now, None, Some(300))),
+            vec![1, 4, 2]
+        );
+        assert_eq!(
+            ids(select_for_prune(&caches, now, Some(week), Some(500))),
+            vec![1, 4]
+        );
+        assert!(select_for_prune(&caches, now, None, None).is_empty());
+    }
+
+    fn interaction(args: &[&str], stdout: &str, exit_code: i32) -> Interaction {
+        Interaction {
+            args: args.iter().map(|s| s.to_string()).collect(),
+            env: BTreeMap::new(),
+            stdin: None,
+            stdout: stdout.to_string(),
+            stderr: if exit_code == 0 {
+                String::new()
+            } else {
+                "HTTP 404: Not Found".to_string()
+            },
+            exit_code,
+        }
+    }
+
+    fn list_args(fields: &str) -> Vec<&str> {
+        vec![
+            "cache", "list", "--limit", "10000", "--repo", "octo/app", "--key", "cargo-", "--json",
+            fields,
+        ]
+    }
+
+    fn listing(ids: impl Iterator<Item = u64>) -> String {
+        let entries: Vec<String> = ids
+            .map(|id| {
+                format!(
+                    r#"{{"id": {}, "key": "cargo-{}", "ref": "refs/heads/main", "sizeInBytes": 100,
+                        "createdAt": "2024-01-01T00:00:00Z", "lastAccessedAt": "2024-01-01T00:00:00Z"}}"#,
+                    id, id
+                )
+            })
+            .collect();
+        format!("[{}]", entries.join(","))
+    }
+
+    fn prune(interactions: Vec<Interaction>) -> CachePruneCommand {
+        let executor =
+            GhExecutor::default().with_cassette(Cassette::from_interactions(interactions));
+        CachePruneCommand::new(Arc::new(executor))
+            .repo("octo/app")
+            .key_prefix("cargo-")
+            .max_total_size(0)
+    }
+
+    #[test]
+    fn test_prune_reports_deleted_and_failed() {
+        let fields = CACHE_FIELDS.join(",");
+        let report = prune(vec![
+            interaction(&list_args(&fields), &listing(1..4), 0),
+            interaction(&["cache", "delete", "1", "--repo", "octo/app"], "", 0),
+            interaction(&["cache", "delete", "2", "--repo", "octo/app"], "", 1),
+            interaction(&["cache", "delete", "3", "--repo", "octo/app"], "", 0),
+        ])
+        .execute()
+        .unwrap();
+
+        let deleted: Vec<u64> = report.deleted.iter().map(|c| c.id).collect();
+        assert_eq!(deleted, vec![1, 3]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0.id, 2);
+        assert!(matches!(
+            report.into_result(),
+            Err(GhError::CommandFailed { code: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_prune_refuses_truncated_listing() {
+        let fields = CACHE_FIELDS.join(",");
+        let command = prune(vec![interaction(
+            &list_args(&fields),
+            &listing(0..u64::from(PRUNE_LIST_LIMIT)),
+            0,
+        )]);
+        assert!(matches!(command.plan(), Err(GhError::InvalidCommand(_))));
+    }
+}
@@ -0,0 +1,757 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::error::{GhError, Result};
+use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Codespace commands namespace
+#[derive(Clone)]
+pub struct CodespaceCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl CodespaceCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// Create a codespace
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// # use std::time::Duration;
+    /// let client = GhClient::new();
+    /// let name = client
+    ///     .codespace()
+    ///     .create("octo/app")
+    ///     .branch("main")
+    ///     .machine("basicLinux32gb")
+    ///     .idle_timeout(Duration::from_secs(30 * 60))
+    ///     .execute();
+    /// ```
+    pub fn create(&self, repo: &str) -> CodespaceCreateCommand {
+        CodespaceCreateCommand::new(self.executor.clone(), repo)
+    }
+
+    /// List codespaces
+    pub fn list(&self) -> CodespaceListCommand {
+        CodespaceListCommand::new(self.executor.clone())
+    }
+
+    /// View a codespace
+    pub fn view(&self, name: &str) -> CodespaceViewCommand {
+        CodespaceViewCommand::new(self.executor.clone(), name)
+    }
+
+    /// Stop a running codespace
+    pub fn stop(&self, name: &str) -> CodespaceStopCommand {
+        CodespaceStopCommand::new(self.executor.clone(), name)
+    }
+
+    /// Delete codespaces, selected by name, repository or age
+    pub fn delete(&self) -> CodespaceDeleteCommand {
+        CodespaceDeleteCommand::new(self.executor.clone())
+    }
+
+    /// Edit a codespace
+    pub fn edit(&self, name: &str) -> CodespaceEditCommand {
+        CodespaceEditCommand::new(self.executor.clone(), name)
+    }
+
+    /// List the forwarded ports of a codespace
+    pub fn ports(&self, name: &str) -> CodespacePortsCommand {
+        CodespacePortsCommand::new(self.executor.clone(), name)
+    }
+
+    /// Change the visibility of forwarded ports
+    pub fn set_port_visibility(
+        &self,
+        name: &str,
+        ports: &[(u16, PortVisibility)],
+    ) -> CodespacePortVisibilityCommand {
+        CodespacePortVisibilityCommand::new(self.executor.clone(), name, ports)
+    }
+
+    /// Access the logs of a codespace
+    pub fn logs(&self, name: &str) -> CodespaceLogsCommand {
+        CodespaceLogsCommand::new(self.executor.clone(), name)
+    }
+
+    /// Copy files between the local machine and a codespace
+    pub fn cp(&self, name: &str) -> CodespaceCopyCommand {
+        CodespaceCopyCommand::new(self.executor.clone(), name)
+    }
+}
+
+/// Format a duration the way gh parses it (e.g. `30m`, `90s`)
+fn go_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs % 3600 == 0 && secs > 0 {
+        format!("{}h", secs / 3600)
+    } else if secs % 60 == 0 && secs > 0 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// Command for creating a codespace
+pub struct CodespaceCreateCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CodespaceCreateCommand {
+    fn new(executor: Arc<GhExecutor>, repo: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["codespace", "create"]).option("--repo", repo),
+        }
+    }
+
+    /// Branch to check out
+    pub fn branch(mut self, branch: &str) -> Self {
+        self.cmd = self.cmd.option("--branch", branch);
+        self
+    }
+
+    /// Machine type (e.g. `basicLinux32gb`)
+    pub fn machine(mut self, machine: &str) -> Self {
+        self.cmd = self.cmd.option("--machine", machine);
+        self
+    }
+
+    /// Path to the devcontainer.json file to use
+    pub fn devcontainer_path(mut self, path: &str) -> Self {
+        self.cmd = self.cmd.option("--devcontainer-path", path);
+        self
+    }
+
+    /// Inactivity period after which the codespace is stopped
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.cmd = self.cmd.option("--idle-timeout", &go_duration(timeout));
+        self
+    }
+
+    /// How long a stopped codespace is kept before it is deleted
+    pub fn retention_period(mut self, period: Duration) -> Self {
+        self.cmd = self.cmd.option("--retention-period", &go_duration(period));
+        self
+    }
+
+    /// Display name
+    pub fn display_name(mut self, name: &str) -> Self {
+        self.cmd = self.cmd.option("--display-name", name);
+        self
+    }
+
+    /// Location (`EastUs`, `SouthEastAsia`, `WestEurope` or `WestUs2`)
+    pub fn location(mut self, location: &str) -> Self {
+        self.cmd = self.cmd.option("--location", location);
+        self
+    }
+
+    /// Execute the create command and return the codespace name
+    pub fn execute(&self) -> Result<String> {
+        let output = GhCommand::execute(self, self.executor.as_ref())?;
+        Ok(output.trim().to_string())
+    }
+}
+
+impl GhCommand for CodespaceCreateCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Lifecycle state of a codespace
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum CodespaceState {
+    Available,
+    Starting,
+    Shutdown,
+    ShuttingDown,
+    Provisioning,
+    Rebuilding,
+    Queued,
+    Created,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Git status of a codespace
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodespaceGitStatus {
+    #[serde(default)]
+    pub ahead: u32,
+    #[serde(default)]
+    pub behind: u32,
+    #[serde(default, rename = "ref")]
+    pub git_ref: String,
+    #[serde(default)]
+    pub has_uncommitted_changes: bool,
+    #[serde(default)]
+    pub has_unpushed_changes: bool,
+}
+
+const CODESPACE_FIELDS: &[&str] = &[
+    "name",
+    "displayName",
+    "state",
+    "owner",
+    "repository",
+    "gitStatus",
+    "machineName",
+    "createdAt",
+    "lastUsedAt",
+];
+
+/// A codespace returned by `CodespaceListCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Codespace {
+    pub name: String,
+    #[serde(default)]
+    pub display_name: String,
+    pub state: CodespaceState,
+    /// Login of the owner
+    pub owner: String,
+    /// `owner/name` of the repository
+    pub repository: String,
+    #[serde(default)]
+    pub git_status: CodespaceGitStatus,
+    #[serde(default)]
+    pub machine_name: String,
+    pub created_at: Timestamp,
+    pub last_used_at: Timestamp,
+}
+
+/// Command for listing codespaces
+pub struct CodespaceListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CodespaceListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["codespace", "list"]),
+        }
+    }
+
+    /// Only codespaces of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Codespaces of an organization (requires admin)
+    pub fn org(mut self, org: &str) -> Self {
+        self.cmd = self.cmd.option("--org", org);
+        self
+    }
+
+    /// Codespaces of a user in the organization given with `.org()`
+    pub fn user(mut self, user: &str) -> Self {
+        self.cmd = self.cmd.option("--user", user);
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the codespaces
+    pub fn fetch(&self) -> Result<Vec<Codespace>> {
+        let cmd = self
+            .cmd
+            .clone()
+            .option("--json", &CODESPACE_FIELDS.join(","));
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for CodespaceListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+const CODESPACE_DETAIL_FIELDS: &[&str] = &[
+    "name",
+    "displayName",
+    "state",
+    "owner",
+    "billableOwner",
+    "location",
+    "repository",
+    "gitStatus",
+    "devcontainerPath",
+    "machineName",
+    "machineDisplayName",
+    "prebuild",
+    "createdAt",
+    "lastUsedAt",
+    "idleTimeoutMinutes",
+    "retentionPeriodDays",
+];
+
+/// Codespace details returned by `CodespaceViewCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodespaceDetail {
+    pub name: String,
+    #[serde(default)]
+    pub display_name: String,
+    pub state: CodespaceState,
+    pub owner: String,
+    #[serde(default)]
+    pub billable_owner: String,
+    #[serde(default)]
+    pub location: String,
+    pub repository: String,
+    #[serde(default)]
+    pub git_status: CodespaceGitStatus,
+    #[serde(default)]
+    pub devcontainer_path: String,
+    #[serde(default)]
+    pub machine_name: String,
+    #[serde(default)]
+    pub machine_display_name: String,
+    #[serde(default)]
+    pub prebuild: bool,
+    pub created_at: Timestamp,
+    pub last_used_at: Timestamp,
+    #[serde(default)]
+    pub idle_timeout_minutes: u32,
+    #[serde(default)]
+    pub retention_period_days: u32,
+}
+
+/// Command for viewing a codespace
+pub struct CodespaceViewCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CodespaceViewCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["codespace", "view"]).option("--codespace", name),
+        }
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the codespace
+    pub fn fetch(&self) -> Result<CodespaceDetail> {
+        let cmd = self
+            .cmd
+            .clone()
+            .option("--json", &CODESPACE_DETAIL_FIELDS.join(","));
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for CodespaceViewCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for stopping a codespace
+pub struct CodespaceStopCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CodespaceStopCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["codespace", "stop"]).option("--codespace", name),
+        }
+    }
+
+    /// Organization owning the codespace (requires admin)
+    pub fn org(mut self, org: &str) -> Self {
+        self.cmd = self.cmd.option("--org", org);
+        self
+    }
+
+    /// User owning the codespace in the organization given with `.org()`
+    pub fn user(mut self, user: &str) -> Self {
+        self.cmd = self.cmd.option("--user", user);
+        self
+    }
+
+    /// Execute the stop command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for CodespaceStopCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for deleting codespaces
+pub struct CodespaceDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CodespaceDeleteCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["codespace", "delete"]),
+        }
+    }
+
+    /// Delete the named codespace
+    pub fn codespace(mut self, name: &str) -> Self {
+        self.cmd = self.cmd.option("--codespace", name);
+        self
+    }
+
+    /// Delete all codespaces
+    pub fn all(mut self) -> Self {
+        self.cmd = self.cmd.flag("--all");
+        self
+    }
+
+    /// Delete the codespaces of a repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Only delete codespaces unused for at least the given number of days
+    pub fn older_than_days(mut self, days: u32) -> Self {
+        self.cmd = self.cmd.option("--days", &days.to_string());
+        self
+    }
+
+    /// Organization owning the codespaces (requires admin)
+    pub fn org(mut self, org: &str) -> Self {
+        self.cmd = self.cmd.option("--org", org);
+        self
+    }
+
+    /// User owning the codespaces in the organization given with `.org()`
+    pub fn user(mut self, user: &str) -> Self {
+        self.cmd = self.cmd.option("--user", user);
+        self
+    }
+
+    /// Delete without confirmation, even with unsaved changes
+    pub fn force(mut self) -> Self {
+        self.cmd = self.cmd.flag("--force");
+        self
+    }
+
+    /// Execute the delete command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for CodespaceDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for editing a codespace
+pub struct CodespaceEditCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CodespaceEditCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["codespace", "edit"]).option("--codespace", name),
+        }
+    }
+
+    /// Set the display name
+    pub fn display_name(mut self, name: &str) -> Self {
+        self.cmd = self.cmd.option("--display-name", name);
+        self
+    }
+
+    /// Change the machine type
+    pub fn machine(mut self, machine: &str) -> Self {
+        self.cmd = self.cmd.option("--machine", machine);
+        self
+    }
+
+    /// Execute the edit command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for CodespaceEditCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Who can access a forwarded port
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PortVisibility {
+    Private,
+    Org,
+    Public,
+}
+
+impl PortVisibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PortVisibility::Private => "private",
+            PortVisibility::Org => "org",
+            PortVisibility::Public => "public",
+        }
+    }
+}
+
+/// A forwarded port of a codespace
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodespacePort {
+    #[serde(default)]
+    pub label: String,
+    pub source_port: u16,
+    pub visibility: PortVisibility,
+    #[serde(default)]
+    pub browse_url: String,
+}
+
+/// Command for listing forwarded ports
+pub struct CodespacePortsCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CodespacePortsCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["codespace", "ports"]).option("--codespace", name),
+        }
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the ports
+    pub fn fetch(&self) -> Result<Vec<CodespacePort>> {
+        let cmd = self
+            .cmd
+            .clone()
+            .option("--json", "label,sourcePort,visibility,browseUrl");
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for CodespacePortsCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for changing the visibility of forwarded ports
+pub struct CodespacePortVisibilityCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CodespacePortVisibilityCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str, ports: &[(u16, PortVisibility)]) -> Self {
+        let mut cmd = BaseCommand::with_subcommands(&["codespace", "ports", "visibility"]);
+        for (port, visibility) in ports {
+            cmd = cmd.arg(&format!("{}:{}", port, visibility.as_str()));
+        }
+        Self {
+            executor,
+            cmd: cmd.option("--codespace", name),
+        }
+    }
+
+    /// Execute the visibility command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for CodespacePortVisibilityCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for accessing codespace logs
+///
+/// Returns the logs written so far; `--follow` is not exposed since it never exits.
+pub struct CodespaceLogsCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl CodespaceLogsCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["codespace", "logs"]).option("--codespace", name),
+        }
+    }
+
+    /// Execute and get the logs
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for CodespaceLogsCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for copying files to or from a codespace
+///
+/// Remote paths are relative to the codespace home directory.
+pub struct CodespaceCopyCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    sources: Vec<String>,
+    destination: Option<String>,
+}
+
+impl CodespaceCopyCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["codespace", "cp"]).option("--codespace", name),
+            sources: Vec::new(),
+            destination: None,
+        }
+    }
+
+    /// Copy a local file
+    pub fn from_local(mut self, path: &str) -> Self {
+        self.sources.push(path.to_string());
+        self
+    }
+
+    /// Copy a file from the codespace
+    pub fn from_remote(mut self, path: &str) -> Self {
+        self.sources.push(format!("remote:{}", path));
+        self
+    }
+
+    /// Copy to a local path
+    pub fn to_local(mut self, path: &str) -> Self {
+        self.destination = Some(path.to_string());
+        self
+    }
+
+    /// Copy to a path in the codespace
+    pub fn to_remote(mut self, path: &str) -> Self {
+        self.destination = Some(format!("remote:{}", path));
+        self
+    }
+
+    /// Copy directories recursively
+    pub fn recursive(mut self) -> Self {
+        self.cmd = self.cmd.flag("--recursive");
+        self
+    }
+
+    /// Expand remote paths with the codespace shell
+    pub fn expand(mut self) -> Self {
+        self.cmd = self.cmd.flag("--expand");
+        self
+    }
+
+    /// Execute the copy command
+    pub fn execute(&self) -> Result<String> {
+        if self.sources.is_empty() || self.destination.is_none() {
+            return Err(GhError::InvalidCommand(
+                "codespace cp requires at least one source and a destination".to_string(),
+            ));
+        }
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for CodespaceCopyCommand {
+    fn build_args(&self) -> Vec<String> {
+        let mut args = self.cmd.build_args();
+        args.extend(self.sources.iter().cloned());
+        args.extend(self.destination.clone());
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_go_duration() {
+        assert_eq!(go_duration(Duration::from_secs(30 * 60)), "30m");
+        assert_eq!(go_duration(Duration::from_secs(72 * 3600)), "72h");
+        assert_eq!(go_duration(Duration::from_secs(90)), "90s");
+        assert_eq!(go_duration(Duration::ZERO), "0s");
+    }
+
+    #[test]
+    fn test_cp_args() {
+        let executor = Arc::new(GhExecutor::default());
+        let cp = CodespaceCopyCommand::new(executor.clone(), "cs-1")
+            .recursive()
+            .from_remote("logs")
+            .to_local("./logs");
+        assert_eq!(
+            cp.build_args(),
+            vec![
+                "codespace",
+                "cp",
+                "--codespace",
+                "cs-1",
+                "--recursive",
+                "remote:logs",
+                "./logs"
+            ]
+        );
+
+        let cp = CodespaceCopyCommand::new(executor, "cs-1").from_local("a.txt");
+        assert!(matches!(cp.execute(), Err(GhError::InvalidCommand(_))));
+    }
+}
@@ -0,0 +1,246 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// gh configuration commands namespace
+#[derive(Clone)]
+pub struct ConfigCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl ConfigCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// Get a configuration value
+    pub fn get(&self, key: &str) -> ConfigGetCommand {
+        ConfigGetCommand::new(self.executor.clone(), key)
+    }
+
+    /// Set a configuration value
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// client.config().set("git_protocol", "ssh").host("github.com").execute();
+    /// client.config().set("prompt", "disabled").execute();
+    /// ```
+    pub fn set(&self, key: &str, value: &str) -> ConfigSetCommand {
+        ConfigSetCommand::new(self.executor.clone(), key, value)
+    }
+
+    /// List configuration values
+    pub fn list(&self) -> ConfigListCommand {
+        ConfigListCommand::new(self.executor.clone())
+    }
+
+    /// Clear the cache of API responses
+    pub fn clear_cache(&self) -> ConfigClearCacheCommand {
+        ConfigClearCacheCommand::new(self.executor.clone())
+    }
+}
+
+/// Configuration returned by `ConfigListCommand::fetch`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GhConfig {
+    /// `https` or `ssh`
+    pub git_protocol: Option<String>,
+    pub editor: Option<String>,
+    /// `enabled` or `disabled`
+    pub prompt: Option<String>,
+    pub pager: Option<String>,
+    pub http_unix_socket: Option<String>,
+    pub browser: Option<String>,
+    /// Any other keys
+    pub other: BTreeMap<String, String>,
+}
+
+impl GhConfig {
+    /// Parse `key=value` lines of `gh config list` output
+    fn parse(output: &str) -> Self {
+        let mut config = GhConfig::default();
+        for line in output.lines() {
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim().to_string()),
+                None => continue,
+            };
+            let value = Some(value).filter(|v| !v.is_empty());
+            match key {
+                "git_protocol" => config.git_protocol = value,
+                "editor" => config.editor = value,
+                "prompt" => config.prompt = value,
+                "pager" => config.pager = value,
+                "http_unix_socket" => config.http_unix_socket = value,
+                "browser" => config.browser = value,
+                _ => {
+                    config
+                        .other
+                        .insert(key.to_string(), value.unwrap_or_default());
+                }
+            }
+        }
+        config
+    }
+
+    /// Look up any key by name
+    pub fn get(&self, key: &str) -> Option<&str> {
+        let value = match key {
+            "git_protocol" => &self.git_protocol,
+            "editor" => &self.editor,
+            "prompt" => &self.prompt,
+            "pager" => &self.pager,
+            "http_unix_socket" => &self.http_unix_socket,
+            "browser" => &self.browser,
+            _ => return self.other.get(key).map(String::as_str),
+        };
+        value.as_deref()
+    }
+}
+
+/// Command for getting a configuration value
+pub struct ConfigGetCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ConfigGetCommand {
+    fn new(executor: Arc<GhExecutor>, key: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["config", "get"]).arg(key),
+        }
+    }
+
+    /// Get the value for a specific host
+    pub fn host(mut self, host: &str) -> Self {
+        self.cmd = self.cmd.option("--host", host);
+        self
+    }
+
+    /// Execute and get the value
+    pub fn execute(&self) -> Result<String> {
+        let output = GhCommand::execute(self, self.executor.as_ref())?;
+        Ok(output.trim_end().to_string())
+    }
+}
+
+impl GhCommand for ConfigGetCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for setting a configuration value
+pub struct ConfigSetCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ConfigSetCommand {
+    fn new(executor: Arc<GhExecutor>, key: &str, value: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["config", "set"]).args(&[key, value]),
+        }
+    }
+
+    /// Set the value for a specific host
+    pub fn host(mut self, host: &str) -> Self {
+        self.cmd = self.cmd.option("--host", host);
+        self
+    }
+
+    /// Execute the set command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for ConfigSetCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for listing configuration values
+pub struct ConfigListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ConfigListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["config", "list"]),
+        }
+    }
+
+    /// List the values for a specific host
+    pub fn host(mut self, host: &str) -> Self {
+        self.cmd = self.cmd.option("--host", host);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the configuration
+    pub fn fetch(&self) -> Result<GhConfig> {
+        Ok(GhConfig::parse(&self.execute()?))
+    }
+}
+
+impl GhCommand for ConfigListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for clearing the API response cache
+pub struct ConfigClearCacheCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ConfigClearCacheCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["config", "clear-cache"]),
+        }
+    }
+
+    /// Execute the clear-cache command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for ConfigClearCacheCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_parsing() {
+        let config = GhConfig::parse(
+            "git_protocol=ssh\neditor=\nprompt=disabled\nspinner=enabled\nnot a setting\n",
+        );
+        assert_eq!(config.git_protocol.as_deref(), Some("ssh"));
+        assert_eq!(config.editor, None);
+        assert_eq!(config.get("prompt"), Some("disabled"));
+        assert_eq!(config.get("spinner"), Some("enabled"));
+        assert_eq!(config.get("missing"), None);
+    }
+}
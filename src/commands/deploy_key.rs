@@ -0,0 +1,256 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::key_input::KeyInput;
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Deploy key commands, accessed through `RepoCommands::deploy_key`
+#[derive(Clone)]
+pub struct DeployKeyCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl DeployKeyCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List deploy keys
+    pub fn list(&self) -> DeployKeyListCommand {
+        DeployKeyListCommand::new(self.executor.clone())
+    }
+
+    /// Add a deploy key
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// client
+    ///     .repo()
+    ///     .deploy_key()
+    ///     .add()
+    ///     .key_file("deploy.pub")
+    ///     .title("release bot")
+    ///     .allow_write()
+    ///     .repo("octo/app")
+    ///     .execute();
+    /// ```
+    pub fn add(&self) -> DeployKeyAddCommand {
+        DeployKeyAddCommand::new(self.executor.clone())
+    }
+
+    /// Delete a deploy key by ID
+    pub fn delete(&self, id: u64) -> DeployKeyDeleteCommand {
+        DeployKeyDeleteCommand::new(self.executor.clone(), id)
+    }
+}
+
+/// A deploy key returned by `DeployKeyListCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeployKey {
+    pub id: u64,
+    #[serde(default)]
+    pub title: String,
+    pub key: String,
+    /// Whether the key lacks write access
+    #[serde(default)]
+    pub read_only: bool,
+    pub created_at: Timestamp,
+}
+
+/// Command for listing deploy keys
+pub struct DeployKeyListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl DeployKeyListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["repo", "deploy-key", "list"]),
+        }
+    }
+
+    /// List deploy keys of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the deploy keys
+    pub fn fetch(&self) -> Result<Vec<DeployKey>> {
+        let cmd = self
+            .cmd
+            .clone()
+            .option("--json", "id,title,key,readOnly,createdAt");
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for DeployKeyListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for adding a deploy key
+///
+/// The key is read from `.key_file()` or, with `.key()`, passed via stdin.
+pub struct DeployKeyAddCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    input: KeyInput,
+}
+
+impl DeployKeyAddCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["repo", "deploy-key", "add"]),
+            input: KeyInput::default(),
+        }
+    }
+
+    /// Public key material to upload
+    pub fn key(mut self, key: &str) -> Self {
+        self.input.key = Some(key.to_string());
+        self
+    }
+
+    /// Path to a public key file to upload
+    pub fn key_file(mut self, path: &str) -> Self {
+        self.input.key_file = Some(path.to_string());
+        self
+    }
+
+    /// Title of the key
+    pub fn title(mut self, title: &str) -> Self {
+        self.cmd = self.cmd.option("--title", title);
+        self
+    }
+
+    /// Grant write access (keys are read-only by default)
+    pub fn allow_write(mut self) -> Self {
+        self.cmd = self.cmd.flag("--allow-write");
+        self
+    }
+
+    /// Add the key to the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute the add command
+    pub fn execute(&self) -> Result<String> {
+        self.input
+            .execute(&self.executor, "deploy-key add", &self.build_args())
+    }
+}
+
+impl GhCommand for DeployKeyAddCommand {
+    fn build_args(&self) -> Vec<String> {
+        let mut args = self.cmd.build_args();
+        args.extend(self.input.arg());
+        args
+    }
+}
+
+/// Command for deleting a deploy key
+pub struct DeployKeyDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl DeployKeyDeleteCommand {
+    fn new(executor: Arc<GhExecutor>, id: u64) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["repo", "deploy-key", "delete"])
+                .arg(&id.to_string()),
+        }
+    }
+
+    /// Delete the key from the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute the delete command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for DeployKeyDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_add_args() {
+        let executor = Arc::new(GhExecutor::default());
+        let add = DeployKeyAddCommand::new(executor.clone())
+            .key_file("deploy.pub")
+            .title("bot")
+            .allow_write()
+            .repo("octo/app");
+        assert_eq!(
+            add.build_args(),
+            vec![
+                "repo",
+                "deploy-key",
+                "add",
+                "--title",
+                "bot",
+                "--allow-write",
+                "--repo",
+                "octo/app",
+                "deploy.pub"
+            ]
+        );
+
+        // Keys given inline are read from stdin, like ssh-key and gpg-key add
+        let add = DeployKeyAddCommand::new(executor).key("ssh-ed25519 AAAA");
+        assert_eq!(add.build_args(), vec!["repo", "deploy-key", "add", "-"]);
+    }
+
+    #[test]
+    fn test_add_writes_inline_key_to_stdin() {
+        let executor =
+            GhExecutor::default().with_cassette(Cassette::from_interactions(vec![Interaction {
+                args: ["repo", "deploy-key", "add", "--repo", "octo/app", "-"]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect(),
+                env: BTreeMap::new(),
+                stdin: Some("ssh-ed25519 AAAA".to_string()),
+                stdout: String::new(),
+                stderr: String::new(),
+                exit_code: 0,
+            }]));
+
+        DeployKeyAddCommand::new(Arc::new(executor))
+            .key("ssh-ed25519 AAAA")
+            .repo("octo/app")
+            .execute()
+            .unwrap();
+    }
+}
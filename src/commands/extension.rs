@@ -0,0 +1,497 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::search::SortOrder;
+use crate::error::{GhError, Result};
+use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Extension commands namespace
+#[derive(Clone)]
+pub struct ExtensionCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl ExtensionCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List installed extensions
+    pub fn list(&self) -> ExtensionListCommand {
+        ExtensionListCommand::new(self.executor.clone())
+    }
+
+    /// Install an extension from a repository (`owner/gh-name`) or local directory
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// client.extension().install("octo/gh-deploy").pin("v1.4.0").execute();
+    /// ```
+    pub fn install(&self, repo: &str) -> ExtensionInstallCommand {
+        ExtensionInstallCommand::new(self.executor.clone(), repo)
+    }
+
+    /// Upgrade an installed extension
+    pub fn upgrade(&self, name: &str) -> ExtensionUpgradeCommand {
+        ExtensionUpgradeCommand::new(self.executor.clone(), Some(name))
+    }
+
+    /// Upgrade all installed extensions
+    pub fn upgrade_all(&self) -> ExtensionUpgradeCommand {
+        ExtensionUpgradeCommand::new(self.executor.clone(), None)
+    }
+
+    /// Remove an installed extension
+    pub fn remove(&self, name: &str) -> ExtensionRemoveCommand {
+        ExtensionRemoveCommand::new(self.executor.clone(), name)
+    }
+
+    /// Scaffold a new extension in a `gh-<name>` directory
+    pub fn create(&self, name: &str) -> ExtensionCreateCommand {
+        ExtensionCreateCommand::new(self.executor.clone(), name)
+    }
+
+    /// Search extensions on GitHub
+    pub fn search(&self, query: &str) -> ExtensionSearchCommand {
+        ExtensionSearchCommand::new(self.executor.clone(), query)
+    }
+}
+
+/// An installed extension returned by `ExtensionListCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledExtension {
+    /// Name used to invoke the extension (`gh <name>`)
+    pub name: String,
+    /// Source repository (`owner/gh-name`), empty for local extensions
+    pub repo: String,
+    /// Installed version or commit, when known
+    pub version: Option<String>,
+}
+
+impl InstalledExtension {
+    /// Parse a line of `gh extension list` output: `gh <name>`, repo, version
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t').map(str::trim);
+        let name = fields.next()?;
+        let name = name.strip_prefix("gh ").unwrap_or(name);
+        if name.is_empty() {
+            return None;
+        }
+        Some(Self {
+            name: name.to_string(),
+            repo: fields.next().unwrap_or_default().to_string(),
+            version: fields
+                .next()
+                .filter(|version| !version.is_empty())
+                .map(str::to_string),
+        })
+    }
+}
+
+/// Command for listing installed extensions
+pub struct ExtensionListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ExtensionListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["extension", "list"]),
+        }
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the installed extensions
+    pub fn fetch(&self) -> Result<Vec<InstalledExtension>> {
+        let output = self.execute()?;
+        output
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                InstalledExtension::parse(line).ok_or_else(|| {
+                    GhError::ExecutionFailed(format!("unexpected extension list output: {}", line))
+                })
+            })
+            .collect()
+    }
+}
+
+impl GhCommand for ExtensionListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for installing an extension
+pub struct ExtensionInstallCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ExtensionInstallCommand {
+    fn new(executor: Arc<GhExecutor>, repo: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["extension", "install"]).arg(repo),
+        }
+    }
+
+    /// Pin to a release tag or commit
+    pub fn pin(mut self, reference: &str) -> Self {
+        self.cmd = self.cmd.option("--pin", reference);
+        self
+    }
+
+    /// Upgrade if the extension is already installed
+    pub fn force(mut self) -> Self {
+        self.cmd = self.cmd.flag("--force");
+        self
+    }
+
+    /// Execute the install command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for ExtensionInstallCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for upgrading extensions
+pub struct ExtensionUpgradeCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ExtensionUpgradeCommand {
+    /// Upgrade the named extension, or all extensions for `None`
+    fn new(executor: Arc<GhExecutor>, name: Option<&str>) -> Self {
+        let cmd = BaseCommand::with_subcommands(&["extension", "upgrade"]);
+        Self {
+            executor,
+            cmd: match name {
+                Some(name) => cmd.arg(name),
+                None => cmd.flag("--all"),
+            },
+        }
+    }
+
+    /// Upgrade pinned extensions too
+    pub fn force(mut self) -> Self {
+        self.cmd = self.cmd.flag("--force");
+        self
+    }
+
+    /// Only report available upgrades
+    pub fn dry_run(mut self) -> Self {
+        self.cmd = self.cmd.flag("--dry-run");
+        self
+    }
+
+    /// Execute the upgrade command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for ExtensionUpgradeCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for removing an extension
+pub struct ExtensionRemoveCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ExtensionRemoveCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["extension", "remove"]).arg(name),
+        }
+    }
+
+    /// Execute the remove command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for ExtensionRemoveCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for scaffolding a new extension
+pub struct ExtensionCreateCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ExtensionCreateCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["extension", "create"]).arg(name),
+        }
+    }
+
+    /// Create a precompiled extension (`go` or `other`) instead of a script
+    pub fn precompiled(mut self, kind: &str) -> Self {
+        self.cmd = self.cmd.flag(&format!("--precompiled={}", kind));
+        self
+    }
+
+    /// Execute the create command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for ExtensionCreateCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+const EXTENSION_SEARCH_FIELDS: &[&str] = &[
+    "fullName",
+    "description",
+    "isInstalled",
+    "isOfficial",
+    "stargazersCount",
+    "updatedAt",
+    "url",
+];
+
+/// An extension returned by `ExtensionSearchCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionSearchResult {
+    pub full_name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub is_installed: bool,
+    /// Maintained by GitHub
+    #[serde(default)]
+    pub is_official: bool,
+    #[serde(default)]
+    pub stargazers_count: u32,
+    pub updated_at: Timestamp,
+    pub url: String,
+}
+
+/// Command for searching extensions
+pub struct ExtensionSearchCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ExtensionSearchCommand {
+    fn new(executor: Arc<GhExecutor>, query: &str) -> Self {
+        let cmd = BaseCommand::with_subcommands(&["extension", "search"]);
+        Self {
+            executor,
+            cmd: if query.is_empty() {
+                cmd
+            } else {
+                cmd.arg(query)
+            },
+        }
+    }
+
+    /// Only extensions owned by this user or organization
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = self.cmd.option("--owner", owner);
+        self
+    }
+
+    /// Only extensions with this license (e.g. `mit`)
+    pub fn license(mut self, license: &str) -> Self {
+        self.cmd = self.cmd.option("--license", license);
+        self
+    }
+
+    /// Sort by `forks`, `help-wanted-issues`, `stars` or `updated`
+    pub fn sort(mut self, sort: &str) -> Self {
+        self.cmd = self.cmd.option("--sort", sort);
+        self
+    }
+
+    /// Sort direction
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.cmd = self.cmd.option("--order", order.as_str());
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the results
+    pub fn fetch(&self) -> Result<Vec<ExtensionSearchResult>> {
+        let cmd = self
+            .cmd
+            .clone()
+            .option("--json", &EXTENSION_SEARCH_FIELDS.join(","));
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for ExtensionSearchCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for running an installed extension
+///
+/// Runs through the client's executor, so retries, rate limiting, env,
+/// cassettes and the client timeout apply as for built-in commands.
+pub struct ExtensionExecCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    input: Option<String>,
+    timeout: Option<Duration>,
+}
+
+impl ExtensionExecCommand {
+    pub(crate) fn new(executor: Arc<GhExecutor>, name: &str, args: &[&str]) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["extension", "exec", name]).args(args),
+            input: None,
+            timeout: None,
+        }
+    }
+
+    /// Append an argument
+    pub fn arg(mut self, arg: &str) -> Self {
+        self.cmd = self.cmd.arg(arg);
+        self
+    }
+
+    /// Pass input to the extension via stdin
+    pub fn input(mut self, input: &str) -> Self {
+        self.input = Some(input.to_string());
+        self
+    }
+
+    /// Kill the extension if it runs longer than `timeout` (overrides the client timeout)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Execute the extension and get its output
+    pub fn execute(&self) -> Result<String> {
+        let executor = match self.timeout {
+            Some(timeout) => Arc::new(self.executor.as_ref().clone().with_timeout(timeout)),
+            None => self.executor.clone(),
+        };
+        match &self.input {
+            Some(input) => executor.execute_with_input(&self.build_args(), input),
+            None => GhCommand::execute(self, executor.as_ref()),
+        }
+    }
+}
+
+impl GhCommand for ExtensionExecCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_installed_extension_parsing() {
+        let ext = InstalledExtension::parse("gh dash\tdlvhdr/gh-dash\tv4.0.0").unwrap();
+        assert_eq!(ext.name, "dash");
+        assert_eq!(ext.repo, "dlvhdr/gh-dash");
+        assert_eq!(ext.version.as_deref(), Some("v4.0.0"));
+
+        let local = InstalledExtension::parse("gh local\t").unwrap();
+        assert_eq!(local.repo, "");
+        assert_eq!(local.version, None);
+    }
+
+    #[test]
+    fn test_exec_args() {
+        let exec =
+            ExtensionExecCommand::new(Arc::new(GhExecutor::default()), "dash", &["--config"])
+                .arg("dash.yml");
+        assert_eq!(
+            exec.build_args(),
+            vec!["extension", "exec", "dash", "--config", "dash.yml"]
+        );
+    }
+
+    #[test]
+    fn test_exec_input_is_written_to_stdin() {
+        let executor =
+            GhExecutor::default().with_cassette(Cassette::from_interactions(vec![Interaction {
+                args: vec![
+                    "extension".to_string(),
+                    "exec".to_string(),
+                    "jq-lint".to_string(),
+                ],
+                env: BTreeMap::new(),
+                stdin: Some("{\"a\": 1}".to_string()),
+                stdout: "ok\n".to_string(),
+                stderr: String::new(),
+                exit_code: 0,
+            }]));
+
+        let output = ExtensionExecCommand::new(Arc::new(executor), "jq-lint", &[])
+            .input("{\"a\": 1}")
+            .execute()
+            .unwrap();
+        assert_eq!(output, "ok\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_exec_timeout() {
+        // `sleep 5` stands in for an extension that hangs on the network
+        let exec = ExtensionExecCommand {
+            executor: Arc::new(GhExecutor::new("sleep".to_string())),
+            cmd: BaseCommand::new("5"),
+            input: None,
+            timeout: None,
+        };
+
+        match exec.timeout(Duration::from_millis(200)).execute() {
+            Err(GhError::Timeout(timeout)) => assert_eq!(timeout, Duration::from_millis(200)),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,709 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::error::{GhError, Result};
+use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Gist commands namespace
+#[derive(Clone)]
+pub struct GistCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl GistCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// Create a gist
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// let url = client
+    ///     .gist()
+    ///     .create()
+    ///     .description("Incident 42 logs")
+    ///     .file("/var/log/app.log")
+    ///     .content("summary.md", "# Summary\n...")
+    ///     .execute();
+    /// ```
+    pub fn create(&self) -> GistCreateCommand {
+        GistCreateCommand::new(self.executor.clone())
+    }
+
+    /// List your gists
+    pub fn list(&self) -> GistListCommand {
+        GistListCommand::new(self.executor.clone())
+    }
+
+    /// View a gist by ID or URL
+    pub fn view(&self, gist: &str) -> GistViewCommand {
+        GistViewCommand::new(self.executor.clone(), gist)
+    }
+
+    /// Edit a gist by ID or URL
+    pub fn edit(&self, gist: &str) -> GistEditCommand {
+        GistEditCommand::new(self.executor.clone(), gist)
+    }
+
+    /// Clone a gist locally
+    pub fn clone(&self, gist: &str) -> GistCloneCommand {
+        GistCloneCommand::new(self.executor.clone(), gist)
+    }
+
+    /// Rename a file in a gist
+    pub fn rename(&self, gist: &str, old_filename: &str, new_filename: &str) -> GistRenameCommand {
+        GistRenameCommand::new(self.executor.clone(), gist, old_filename, new_filename)
+    }
+
+    /// Delete a gist
+    pub fn delete(&self, gist: &str) -> GistDeleteCommand {
+        GistDeleteCommand::new(self.executor.clone(), gist)
+    }
+}
+
+/// Gist ID from an ID or a gist URL
+fn gist_id(gist: &str) -> &str {
+    gist.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or(gist)
+}
+
+/// Check that an in-memory gist file name is a plain file name
+fn check_file_name(name: &str) -> Result<()> {
+    if name.is_empty() || Path::new(name).file_name() != Some(name.as_ref()) {
+        return Err(GhError::InvalidCommand(format!(
+            "gist file name must not contain a path: {:?}",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Command for creating a gist
+pub struct GistCreateCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    paths: Vec<String>,
+    contents: Vec<(String, String)>,
+}
+
+impl GistCreateCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["gist", "create"]),
+            paths: Vec::new(),
+            contents: Vec::new(),
+        }
+    }
+
+    /// Add a file from a local path
+    pub fn file(mut self, path: &str) -> Self {
+        self.paths.push(path.to_string());
+        self
+    }
+
+    /// Add a file from in-memory content
+    ///
+    /// A single in-memory file is passed to `gh gist create` via stdin. With
+    /// several, the gist is created through `gh api gists` instead, sending
+    /// every file (local ones included) as a JSON body on stdin.
+    pub fn content(mut self, filename: &str, content: &str) -> Self {
+        self.contents
+            .push((filename.to_string(), content.to_string()));
+        self
+    }
+
+    /// Set the description
+    pub fn description(mut self, desc: &str) -> Self {
+        self.cmd = self.cmd.option("--desc", desc);
+        self
+    }
+
+    /// Make the gist public (gists are secret by default)
+    pub fn public(mut self) -> Self {
+        self.cmd = self.cmd.flag("--public");
+        self
+    }
+
+    /// Execute the create command and return the gist URL
+    pub fn execute(&self) -> Result<String> {
+        let args = self.build_args();
+        let output = match self.contents.as_slice() {
+            [] => self.executor.execute(&args)?,
+            [(name, content)] => {
+                check_file_name(name)?;
+                self.executor.execute_with_input(&args, content)?
+            }
+            _ => self.executor.execute_with_input(&args, &self.api_body()?)?,
+        };
+        Ok(output.trim().to_string())
+    }
+
+    /// JSON body for `POST /gists` holding every file
+    fn api_body(&self) -> Result<String> {
+        let mut files = serde_json::Map::new();
+        for path in &self.paths {
+            let name = Path::new(path)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .ok_or_else(|| GhError::InvalidCommand(format!("not a file: {:?}", path)))?;
+            let content = fs::read_to_string(path)?;
+            files.insert(name, serde_json::json!({ "content": content }));
+        }
+        for (name, content) in &self.contents {
+            check_file_name(name)?;
+            files.insert(name.clone(), serde_json::json!({ "content": content }));
+        }
+        let body = serde_json::json!({
+            "description": self.cmd.values("--desc").pop().unwrap_or(""),
+            "public": self.cmd.args.iter().any(|arg| arg == "--public"),
+            "files": files,
+        });
+        Ok(body.to_string())
+    }
+}
+
+impl GhCommand for GistCreateCommand {
+    fn build_args(&self) -> Vec<String> {
+        match self.contents.as_slice() {
+            [] => {
+                let mut args = self.cmd.build_args();
+                args.extend(self.paths.iter().cloned());
+                args
+            }
+            [(name, _)] => {
+                let mut args = self.cmd.build_args();
+                args.extend(self.paths.iter().cloned());
+                args.extend(["--filename".to_string(), name.clone(), "-".to_string()]);
+                args
+            }
+            _ => [
+                "api",
+                "-X",
+                "POST",
+                "gists",
+                "--input",
+                "-",
+                "--jq",
+                ".html_url",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+        }
+    }
+}
+
+/// A gist returned by `GistListCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GistSummary {
+    pub id: String,
+    pub description: String,
+    pub file_count: u32,
+    pub public: bool,
+    pub updated_at: Timestamp,
+}
+
+impl GistSummary {
+    /// Parse a line of `gh gist list` output: ID, description, files, visibility, updated
+    fn parse(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        match fields.as_slice() {
+            [id, description, files, visibility, updated] => Some(Self {
+                id: id.to_string(),
+                description: description.to_string(),
+                file_count: files.split_whitespace().next()?.parse().ok()?,
+                public: *visibility == "public",
+                updated_at: Timestamp::new(*updated),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Command for listing gists
+pub struct GistListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl GistListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["gist", "list"]),
+        }
+    }
+
+    /// Only public gists
+    pub fn public(mut self) -> Self {
+        self.cmd = self.cmd.flag("--public");
+        self
+    }
+
+    /// Only secret gists
+    pub fn secret(mut self) -> Self {
+        self.cmd = self.cmd.flag("--secret");
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the gists
+    pub fn fetch(&self) -> Result<Vec<GistSummary>> {
+        let output = self.execute()?;
+        output
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                GistSummary::parse(line).ok_or_else(|| {
+                    GhError::ExecutionFailed(format!("unexpected gist list output: {}", line))
+                })
+            })
+            .collect()
+    }
+}
+
+impl GhCommand for GistListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// A file in a gist
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GistFile {
+    pub filename: String,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub raw_url: String,
+    /// File content (may be truncated by GitHub for large files)
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct GistOwner {
+    login: String,
+}
+
+/// Gist details returned by `GistViewCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GistDetail {
+    pub id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub public: bool,
+    pub html_url: String,
+    /// Files keyed by file name
+    pub files: BTreeMap<String, GistFile>,
+    #[serde(default, deserialize_with = "owner_login")]
+    pub owner: Option<String>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+fn owner_login<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error> {
+    Ok(Option::<GistOwner>::deserialize(deserializer)?.map(|owner| owner.login))
+}
+
+/// Command for viewing a gist
+pub struct GistViewCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    id: String,
+}
+
+impl GistViewCommand {
+    fn new(executor: Arc<GhExecutor>, gist: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["gist", "view"]).arg(gist),
+            id: gist_id(gist).to_string(),
+        }
+    }
+
+    /// Show a single file
+    pub fn filename(mut self, filename: &str) -> Self {
+        self.cmd = self.cmd.option("--filename", filename);
+        self
+    }
+
+    /// List file names instead of contents
+    pub fn files(mut self) -> Self {
+        self.cmd = self.cmd.flag("--files");
+        self
+    }
+
+    /// Print raw content without rendering
+    pub fn raw(mut self) -> Self {
+        self.cmd = self.cmd.flag("--raw");
+        self
+    }
+
+    /// Open in web browser
+    pub fn web(mut self) -> Self {
+        self.cmd = self.cmd.flag("--web");
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Fetch the gist with its files from the API (`gh gist view` has no JSON output)
+    pub fn fetch(&self) -> Result<GistDetail> {
+        let endpoint = format!("gists/{}", self.id);
+        self.executor.execute_json(&["api".to_string(), endpoint])
+    }
+
+    /// Get the web URL without opening a browser
+    pub fn url(&self) -> Result<String> {
+        let args = ["api", &format!("gists/{}", self.id), "--jq", ".html_url"].map(String::from);
+        Ok(self.executor.execute(&args)?.trim().to_string())
+    }
+}
+
+impl GhCommand for GistViewCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for editing a gist
+pub struct GistEditCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    source: Option<String>,
+    input: Option<String>,
+}
+
+impl GistEditCommand {
+    fn new(executor: Arc<GhExecutor>, gist: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["gist", "edit"]).arg(gist),
+            source: None,
+            input: None,
+        }
+    }
+
+    /// Add a new file from a local path
+    pub fn add_file(mut self, path: &str) -> Self {
+        self.cmd = self.cmd.option("--add", path);
+        self
+    }
+
+    /// Replace the content of a file with in-memory content (via stdin)
+    pub fn replace_file(mut self, filename: &str, content: &str) -> Self {
+        self.cmd = self.cmd.option("--filename", filename);
+        self.source = Some("-".to_string());
+        self.input = Some(content.to_string());
+        self
+    }
+
+    /// Replace the content of a file with the content of a local file
+    pub fn replace_file_from(mut self, filename: &str, path: &str) -> Self {
+        self.cmd = self.cmd.option("--filename", filename);
+        self.source = Some(path.to_string());
+        self.input = None;
+        self
+    }
+
+    /// Remove a file
+    pub fn remove_file(mut self, filename: &str) -> Self {
+        self.cmd = self.cmd.option("--remove", filename);
+        self
+    }
+
+    /// Set the description
+    pub fn description(mut self, desc: &str) -> Self {
+        self.cmd = self.cmd.option("--desc", desc);
+        self
+    }
+
+    /// Execute the edit command
+    pub fn execute(&self) -> Result<String> {
+        match &self.input {
+            Some(input) => self.executor.execute_with_input(&self.build_args(), input),
+            None => GhCommand::execute(self, self.executor.as_ref()),
+        }
+    }
+}
+
+impl GhCommand for GistEditCommand {
+    fn build_args(&self) -> Vec<String> {
+        let mut args = self.cmd.build_args();
+        args.extend(self.source.clone());
+        args
+    }
+}
+
+/// Command for cloning a gist
+pub struct GistCloneCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl GistCloneCommand {
+    fn new(executor: Arc<GhExecutor>, gist: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["gist", "clone"]).arg(gist),
+        }
+    }
+
+    /// Clone into the given directory
+    pub fn directory(mut self, directory: &str) -> Self {
+        self.cmd = self.cmd.arg(directory);
+        self
+    }
+
+    /// Execute the clone command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for GistCloneCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for renaming a file in a gist
+pub struct GistRenameCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl GistRenameCommand {
+    fn new(executor: Arc<GhExecutor>, gist: &str, old_filename: &str, new_filename: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["gist", "rename"]).args(&[
+                gist,
+                old_filename,
+                new_filename,
+            ]),
+        }
+    }
+
+    /// Execute the rename command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for GistRenameCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for deleting a gist
+pub struct GistDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl GistDeleteCommand {
+    fn new(executor: Arc<GhExecutor>, gist: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["gist", "delete"]).arg(gist),
+        }
+    }
+
+    /// Confirm deletion without prompting (required when not interactive)
+    pub fn yes(mut self) -> Self {
+        self.cmd = self.cmd.flag("--yes");
+        self
+    }
+
+    /// Execute the delete command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for GistDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+
+    #[test]
+    fn test_gist_id() {
+        assert_eq!(
+            gist_id("5b0e0062eb8e9654adad7bb1d81cc75f"),
+            "5b0e0062eb8e9654adad7bb1d81cc75f"
+        );
+        assert_eq!(
+            gist_id("https://gist.github.com/octocat/5b0e0062eb8e9654adad7bb1d81cc75f/"),
+            "5b0e0062eb8e9654adad7bb1d81cc75f"
+        );
+    }
+
+    #[test]
+    fn test_gist_summary_parsing() {
+        let summary =
+            GistSummary::parse("abc123\tIncident logs\t2 files\tsecret\t2024-05-01T10:00:00Z")
+                .unwrap();
+        assert_eq!(summary.id, "abc123");
+        assert_eq!(summary.file_count, 2);
+        assert!(!summary.public);
+        assert!(GistSummary::parse("garbage").is_none());
+    }
+
+    #[test]
+    fn test_create_args() {
+        let executor = Arc::new(GhExecutor::default());
+        let create = GistCreateCommand::new(executor.clone())
+            .description("logs")
+            .public()
+            .file("app.log");
+        assert_eq!(
+            create.build_args(),
+            vec!["gist", "create", "--desc", "logs", "--public", "app.log"]
+        );
+
+        let create = GistCreateCommand::new(executor.clone())
+            .file("app.log")
+            .content("notes.md", "# Notes");
+        assert_eq!(
+            create.build_args(),
+            vec!["gist", "create", "app.log", "--filename", "notes.md", "-"]
+        );
+
+        let create = GistCreateCommand::new(executor)
+            .content("a.txt", "a")
+            .content("b.txt", "b");
+        assert_eq!(
+            create.build_args(),
+            vec![
+                "api",
+                "-X",
+                "POST",
+                "gists",
+                "--input",
+                "-",
+                "--jq",
+                ".html_url"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_several_contents_replays() {
+        let body = r#"{"description":"pair","files":{"a.txt":{"content":"a"},"b.txt":{"content":"b"}},"public":false}"#;
+        let executor =
+            GhExecutor::default().with_cassette(Cassette::from_interactions(vec![Interaction {
+                args: [
+                    "api",
+                    "-X",
+                    "POST",
+                    "gists",
+                    "--input",
+                    "-",
+                    "--jq",
+                    ".html_url",
+                ]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+                env: BTreeMap::new(),
+                stdin: Some(body.to_string()),
+                stdout: "https://gist.github.com/abc123\n".to_string(),
+                stderr: String::new(),
+                exit_code: 0,
+            }]));
+
+        let url = GistCreateCommand::new(Arc::new(executor))
+            .description("pair")
+            .content("a.txt", "a")
+            .content("b.txt", "b")
+            .execute()
+            .unwrap();
+        assert_eq!(url, "https://gist.github.com/abc123");
+    }
+
+    #[test]
+    fn test_create_rejects_paths_in_names() {
+        let create = GistCreateCommand::new(Arc::new(GhExecutor::default()))
+            .content("../escape.txt", "")
+            .content("ok.txt", "");
+        assert!(matches!(create.execute(), Err(GhError::InvalidCommand(_))));
+    }
+
+    #[test]
+    fn test_edit_args() {
+        let executor = Arc::new(GhExecutor::default());
+        let edit = GistEditCommand::new(executor.clone(), "abc123")
+            .description("renamed")
+            .add_file("extra.txt")
+            .remove_file("old.txt")
+            .replace_file("notes.md", "# Notes");
+        assert_eq!(
+            edit.build_args(),
+            vec![
+                "gist",
+                "edit",
+                "abc123",
+                "--desc",
+                "renamed",
+                "--add",
+                "extra.txt",
+                "--remove",
+                "old.txt",
+                "--filename",
+                "notes.md",
+                "-"
+            ]
+        );
+
+        let edit =
+            GistEditCommand::new(executor, "abc123").replace_file_from("notes.md", "notes.md");
+        assert_eq!(
+            edit.build_args(),
+            vec![
+                "gist",
+                "edit",
+                "abc123",
+                "--filename",
+                "notes.md",
+                "notes.md"
+            ]
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::api::paginated;
+use crate::commands::key_input::KeyInput;
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// GPG key commands namespace
+#[derive(Clone)]
+pub struct GpgKeyCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl GpgKeyCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List GPG keys of the authenticated user
+    pub fn list(&self) -> GpgKeyListCommand {
+        GpgKeyListCommand::new(self.executor.clone())
+    }
+
+    /// Add a GPG key
+    pub fn add(&self) -> GpgKeyAddCommand {
+        GpgKeyAddCommand::new(self.executor.clone())
+    }
+
+    /// Delete a GPG key by key ID (e.g. `3AA5C34371567BD2`)
+    pub fn delete(&self, key_id: &str) -> GpgKeyDeleteCommand {
+        GpgKeyDeleteCommand::new(self.executor.clone(), key_id)
+    }
+}
+
+/// Email address associated with a GPG key
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GpgKeyEmail {
+    pub email: String,
+    #[serde(default)]
+    pub verified: bool,
+}
+
+/// A GPG key returned by `GpgKeyListCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GpgKey {
+    pub id: u64,
+    pub key_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub emails: Vec<GpgKeyEmail>,
+    #[serde(default)]
+    pub can_sign: bool,
+    #[serde(default)]
+    pub can_encrypt_comms: bool,
+    #[serde(default)]
+    pub can_encrypt_storage: bool,
+    pub created_at: Timestamp,
+    #[serde(default)]
+    pub expires_at: Option<Timestamp>,
+}
+
+/// Command for listing GPG keys
+pub struct GpgKeyListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl GpgKeyListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["gpg-key", "list"]),
+        }
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Fetch the keys from the API (`gh gpg-key list` has no JSON output)
+    pub fn fetch(&self) -> Result<Vec<GpgKey>> {
+        self.executor
+            .execute_json_pages(&paginated("user/gpg_keys", &[]))
+    }
+}
+
+impl GhCommand for GpgKeyListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for adding a GPG key
+///
+/// The armored public key is read from `.key_file()` or, with `.key()`,
+/// passed via stdin.
+pub struct GpgKeyAddCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    input: KeyInput,
+}
+
+impl GpgKeyAddCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["gpg-key", "add"]),
+            input: KeyInput::default(),
+        }
+    }
+
+    /// Armored public key to upload
+    pub fn key(mut self, key: &str) -> Self {
+        self.input.key = Some(key.to_string());
+        self
+    }
+
+    /// Path to an armored public key file to upload
+    pub fn key_file(mut self, path: &str) -> Self {
+        self.input.key_file = Some(path.to_string());
+        self
+    }
+
+    /// Title of the key
+    pub fn title(mut self, title: &str) -> Self {
+        self.cmd = self.cmd.option("--title", title);
+        self
+    }
+
+    /// Execute the add command
+    pub fn execute(&self) -> Result<String> {
+        self.input
+            .execute(&self.executor, "gpg-key add", &self.build_args())
+    }
+}
+
+impl GhCommand for GpgKeyAddCommand {
+    fn build_args(&self) -> Vec<String> {
+        let mut args = self.cmd.build_args();
+        args.extend(self.input.arg());
+        args
+    }
+}
+
+/// Command for deleting a GPG key
+pub struct GpgKeyDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl GpgKeyDeleteCommand {
+    fn new(executor: Arc<GhExecutor>, key_id: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["gpg-key", "delete"]).arg(key_id),
+        }
+    }
+
+    /// Confirm deletion without prompting (required when not interactive)
+    pub fn yes(mut self) -> Self {
+        self.cmd = self.cmd.flag("--yes");
+        self
+    }
+
+    /// Execute the delete command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for GpgKeyDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_fetch_reads_every_page() {
+        let key = |id: u64| {
+            format!(
+                r#"{{"id": {}, "key_id": "3AA5C3437156{}", "emails": [{{"email": "a@b.c", "verified": true}}], "created_at": "2024-01-01T00:00:00Z"}}"#,
+                id, id
+            )
+        };
+        let executor =
+            GhExecutor::default().with_cassette(Cassette::from_interactions(vec![Interaction {
+                args: vec![
+                    "api".to_string(),
+                    "--paginate".to_string(),
+                    "user/gpg_keys?per_page=100".to_string(),
+                ],
+                env: BTreeMap::new(),
+                stdin: None,
+                stdout: format!("[{}]\n[{}]\n", key(1), key(2)),
+                stderr: String::new(),
+                exit_code: 0,
+            }]));
+
+        let keys = GpgKeyListCommand::new(Arc::new(executor)).fetch().unwrap();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[1].key_id, "3AA5C34371562");
+        assert!(keys[0].emails[0].verified);
+    }
+
+    #[test]
+    fn test_add_args() {
+        let add = GpgKeyAddCommand::new(Arc::new(GhExecutor::default()))
+            .key("-----BEGIN PGP PUBLIC KEY BLOCK-----")
+            .title("laptop");
+        assert_eq!(
+            add.build_args(),
+            vec!["gpg-key", "add", "--title", "laptop", "-"]
+        );
+    }
+}
@@ -1,6 +1,10 @@
 use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::browse::{query_string, repo_url};
 use crate::error::Result;
 use crate::executor::GhExecutor;
+use crate::selector::IssueSelector;
+use crate::types::{Actor, Comment, Label, Timestamp};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 /// Issue commands namespace
@@ -24,19 +28,19 @@ impl IssueCommands {
         IssueListCommand::new(self.executor.clone())
     }
 
-    /// View an issue
-    pub fn view(&self, number: u32) -> IssueViewCommand {
-        IssueViewCommand::new(self.executor.clone(), number)
+    /// View an issue by number or URL
+    pub fn view(&self, issue: impl Into<IssueSelector>) -> IssueViewCommand {
+        IssueViewCommand::new(self.executor.clone(), issue.into())
     }
 
-    /// Close an issue
-    pub fn close(&self, number: u32) -> IssueCloseCommand {
-        IssueCloseCommand::new(self.executor.clone(), number)
+    /// Close an issue by number or URL
+    pub fn close(&self, issue: impl Into<IssueSelector>) -> IssueCloseCommand {
+        IssueCloseCommand::new(self.executor.clone(), issue.into())
     }
 
-    /// Reopen an issue
-    pub fn reopen(&self, number: u32) -> IssueReopenCommand {
-        IssueReopenCommand::new(self.executor.clone(), number)
+    /// Reopen an issue by number or URL
+    pub fn reopen(&self, issue: impl Into<IssueSelector>) -> IssueReopenCommand {
+        IssueReopenCommand::new(self.executor.clone(), issue.into())
     }
 }
 
@@ -88,6 +92,17 @@ impl IssueCreateCommand {
     pub fn execute(&self) -> Result<String> {
         GhCommand::execute(self, self.executor.as_ref())
     }
+
+    /// Get the URL of the prefilled form `.web()` opens, without creating the issue
+    pub fn url(&self) -> Result<String> {
+        let query = query_string(&[
+            ("title", self.cmd.values("--title").join("")),
+            ("body", self.cmd.values("--body").join("")),
+            ("labels", self.cmd.values("--label").join(",")),
+            ("assignees", self.cmd.values("--assignee").join(",")),
+        ]);
+        Ok(format!("{}/issues/new{}", repo_url(&self.executor)?, query))
+    }
 }
 
 impl GhCommand for IssueCreateCommand {
@@ -152,6 +167,77 @@ impl GhCommand for IssueListCommand {
     }
 }
 
+/// Fields requested by `IssueViewCommand::fetch`
+const ISSUE_DETAIL_FIELDS: &[&str] = &[
+    "number",
+    "title",
+    "body",
+    "state",
+    "stateReason",
+    "url",
+    "author",
+    "labels",
+    "assignees",
+    "milestone",
+    "comments",
+    "createdAt",
+    "updatedAt",
+    "closedAt",
+];
+
+/// State of an issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IssueState {
+    #[serde(alias = "open")]
+    Open,
+    #[serde(alias = "closed")]
+    Closed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Milestone an issue belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Milestone {
+    pub title: String,
+    #[serde(default)]
+    pub number: u32,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub due_on: Option<Timestamp>,
+}
+
+/// Issue details returned by `IssueViewCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueDetail {
+    pub number: u32,
+    pub title: String,
+    #[serde(default)]
+    pub body: String,
+    pub state: IssueState,
+    /// COMPLETED, NOT_PLANNED, REOPENED (empty when never closed)
+    #[serde(default)]
+    pub state_reason: String,
+    pub url: String,
+    pub author: Actor,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub assignees: Vec<Actor>,
+    #[serde(default)]
+    pub milestone: Option<Milestone>,
+    #[serde(default)]
+    pub comments: Vec<Comment>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    #[serde(default)]
+    pub closed_at: Option<Timestamp>,
+}
+
 /// Command for viewing an issue
 pub struct IssueViewCommand {
     executor: Arc<GhExecutor>,
@@ -159,10 +245,10 @@ pub struct IssueViewCommand {
 }
 
 impl IssueViewCommand {
-    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+    fn new(executor: Arc<GhExecutor>, issue: IssueSelector) -> Self {
         Self {
             executor,
-            cmd: BaseCommand::with_subcommands(&["issue", "view"]).arg(&number.to_string()),
+            cmd: issue.apply(BaseCommand::with_subcommands(&["issue", "view"])),
         }
     }
 
@@ -176,6 +262,27 @@ impl IssueViewCommand {
     pub fn execute(&self) -> Result<String> {
         GhCommand::execute(self, self.executor.as_ref())
     }
+
+    /// Execute and parse the issue details, including comments
+    pub fn fetch(&self) -> Result<IssueDetail> {
+        let cmd = self
+            .cmd
+            .without_flag("--web")
+            .option("--json", &ISSUE_DETAIL_FIELDS.join(","));
+        self.executor.execute_json(&cmd.build_args())
+    }
+
+    /// Get the web URL without opening a browser
+    pub fn url(&self) -> Result<String> {
+        let cmd = self
+            .cmd
+            .without_flag("--web")
+            .option("--json", "url")
+            .option("--jq", ".url");
+        Ok(GhCommand::execute(&cmd, self.executor.as_ref())?
+            .trim()
+            .to_string())
+    }
 }
 
 impl GhCommand for IssueViewCommand {
@@ -191,10 +298,10 @@ pub struct IssueCloseCommand {
 }
 
 impl IssueCloseCommand {
-    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+    fn new(executor: Arc<GhExecutor>, issue: IssueSelector) -> Self {
         Self {
             executor,
-            cmd: BaseCommand::with_subcommands(&["issue", "close"]).arg(&number.to_string()),
+            cmd: issue.apply(BaseCommand::with_subcommands(&["issue", "close"])),
         }
     }
 
@@ -217,10 +324,10 @@ pub struct IssueReopenCommand {
 }
 
 impl IssueReopenCommand {
-    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+    fn new(executor: Arc<GhExecutor>, issue: IssueSelector) -> Self {
         Self {
             executor,
-            cmd: BaseCommand::with_subcommands(&["issue", "reopen"]).arg(&number.to_string()),
+            cmd: issue.apply(BaseCommand::with_subcommands(&["issue", "reopen"])),
         }
     }
 
@@ -235,3 +342,89 @@ impl GhCommand for IssueReopenCommand {
         self.cmd.build_args()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    const DETAIL: &str = r#"{
+        "number": 12, "title": "Crash on start", "body": "It crashes", "state": "CLOSED",
+        "stateReason": "COMPLETED", "url": "https://github.com/octo/app/issues/12",
+        "author": {"login": "octocat"},
+        "labels": [{"name": "bug"}],
+        "assignees": [{"login": "hubot", "name": "Hubot", "is_bot": true}],
+        "milestone": {"title": "v1.0", "number": 1, "description": "",
+                      "dueOn": "2024-06-01T00:00:00Z"},
+        "comments": [{"author": {"login": "monalisa"}, "body": "Fixed",
+                      "createdAt": "2024-01-03T00:00:00Z", "authorAssociation": "MEMBER"}],
+        "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-03T00:00:00Z",
+        "closedAt": "2024-01-03T00:00:00Z"
+    }"#;
+
+    #[test]
+    fn test_detail_decodes() {
+        let detail: IssueDetail = serde_json::from_str(DETAIL).unwrap();
+
+        assert_eq!(detail.state, IssueState::Closed);
+        assert_eq!(detail.state_reason, "COMPLETED");
+        assert_eq!(detail.labels[0].name, "bug");
+        assert!(detail.assignees[0].is_bot);
+        let milestone = detail.milestone.unwrap();
+        assert_eq!(milestone.title, "v1.0");
+        assert_eq!(
+            milestone.due_on.map(|due| due.unix_seconds()),
+            Some(Some(1_717_200_000))
+        );
+        assert_eq!(detail.comments[0].author_association, "MEMBER");
+        assert!(detail.closed_at.is_some());
+
+        let minimal = r#"{
+            "number": 13, "title": "Idea", "state": "open",
+            "url": "https://github.com/octo/app/issues/13", "author": {"login": "octocat"},
+            "milestone": null,
+            "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-01T00:00:00Z"
+        }"#;
+        let detail: IssueDetail = serde_json::from_str(minimal).unwrap();
+        assert_eq!(detail.state, IssueState::Open);
+        assert_eq!(detail.milestone, None);
+        assert_eq!(detail.state_reason, "");
+    }
+
+    #[test]
+    fn test_state_falls_back_to_unknown() {
+        let states: Vec<IssueState> =
+            serde_json::from_str(r#"["OPEN", "closed", "DUPLICATE"]"#).unwrap();
+        assert_eq!(
+            states,
+            vec![IssueState::Open, IssueState::Closed, IssueState::Unknown]
+        );
+    }
+
+    #[test]
+    fn test_fetch_ignores_web() {
+        let args = vec![
+            "issue".to_string(),
+            "view".to_string(),
+            "12".to_string(),
+            "--json".to_string(),
+            ISSUE_DETAIL_FIELDS.join(","),
+        ];
+        let executor =
+            GhExecutor::default().with_cassette(Cassette::from_interactions(vec![Interaction {
+                args,
+                env: BTreeMap::new(),
+                stdin: None,
+                stdout: DETAIL.to_string(),
+                stderr: String::new(),
+                exit_code: 0,
+            }]));
+
+        let detail = IssueViewCommand::new(Arc::new(executor), 12.into())
+            .web()
+            .fetch()
+            .unwrap();
+        assert_eq!(detail.number, 12);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::error::{GhError, Result};
+use crate::executor::GhExecutor;
+
+/// Key material for the ssh-key, gpg-key and deploy-key `add` commands
+///
+/// Exactly one of `key` or `key_file` must be set. A key given inline is
+/// written to stdin and the positional argument becomes `-`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct KeyInput {
+    pub(crate) key: Option<String>,
+    pub(crate) key_file: Option<String>,
+}
+
+impl KeyInput {
+    /// Positional argument naming the key source (`-` for stdin)
+    pub(crate) fn arg(&self) -> Option<String> {
+        match (&self.key, &self.key_file) {
+            (Some(_), _) => Some("-".to_string()),
+            (None, path) => path.clone(),
+        }
+    }
+
+    /// Run `args` (which must already include `arg()`), feeding the key to stdin if needed
+    pub(crate) fn execute(
+        &self,
+        executor: &GhExecutor,
+        command: &str,
+        args: &[String],
+    ) -> Result<String> {
+        match (&self.key, &self.key_file) {
+            (Some(key), None) => executor.execute_with_input(args, key),
+            (None, Some(_)) => executor.execute(args),
+            _ => Err(GhError::InvalidCommand(format!(
+                "{} requires exactly one of key or key_file",
+                command
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_key_arg() {
+        let inline = KeyInput {
+            key: Some("ssh-ed25519 AAAA".to_string()),
+            key_file: None,
+        };
+        let file = KeyInput {
+            key: None,
+            key_file: Some("id.pub".to_string()),
+        };
+        assert_eq!(inline.arg().as_deref(), Some("-"));
+        assert_eq!(file.arg().as_deref(), Some("id.pub"));
+        assert_eq!(KeyInput::default().arg(), None);
+    }
+
+    #[test]
+    fn test_key_requires_exactly_one_source() {
+        let executor = GhExecutor::default();
+        let both = KeyInput {
+            key: Some("ssh-ed25519 AAAA".to_string()),
+            key_file: Some("id.pub".to_string()),
+        };
+        for input in [KeyInput::default(), both] {
+            match input.execute(&executor, "ssh-key add", &args(&["ssh-key", "add"])) {
+                Err(GhError::InvalidCommand(message)) => {
+                    assert_eq!(
+                        message,
+                        "ssh-key add requires exactly one of key or key_file"
+                    )
+                }
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_inline_key_is_written_to_stdin() {
+        let executor =
+            GhExecutor::default().with_cassette(Cassette::from_interactions(vec![Interaction {
+                args: args(&["ssh-key", "add", "-"]),
+                env: BTreeMap::new(),
+                stdin: Some("ssh-ed25519 AAAA".to_string()),
+                stdout: "added\n".to_string(),
+                stderr: String::new(),
+                exit_code: 0,
+            }]));
+        let input = KeyInput {
+            key: Some("ssh-ed25519 AAAA".to_string()),
+            key_file: None,
+        };
+        let output = input
+            .execute(&executor, "ssh-key add", &args(&["ssh-key", "add", "-"]))
+            .unwrap();
+        assert_eq!(output, "added\n");
+    }
+}
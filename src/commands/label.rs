@@ -0,0 +1,625 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::error::{GhError, Result};
+use crate::executor::GhExecutor;
+use crate::types::Label;
+use std::sync::Arc;
+
+/// Label commands namespace
+#[derive(Clone)]
+pub struct LabelCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl LabelCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List labels
+    pub fn list(&self) -> LabelListCommand {
+        LabelListCommand::new(self.executor.clone())
+    }
+
+    /// Create a label
+    pub fn create(&self, name: &str) -> LabelCreateCommand {
+        LabelCreateCommand::new(self.executor.clone(), name)
+    }
+
+    /// Edit a label
+    pub fn edit(&self, name: &str) -> LabelEditCommand {
+        LabelEditCommand::new(self.executor.clone(), name)
+    }
+
+    /// Delete a label
+    pub fn delete(&self, name: &str) -> LabelDeleteCommand {
+        LabelDeleteCommand::new(self.executor.clone(), name)
+    }
+
+    /// Clone labels from another repository
+    pub fn clone(&self, source: &str) -> LabelCloneCommand {
+        LabelCloneCommand::new(self.executor.clone(), source)
+    }
+
+    /// Make a repository's labels match the desired set
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::{GhClient, Label};
+    /// let client = GhClient::new();
+    /// let desired = vec![Label {
+    ///     name: "bug".to_string(),
+    ///     color: "d73a4a".to_string(),
+    ///     description: "Something isn't working".to_string(),
+    /// }];
+    /// let report = client
+    ///     .label()
+    ///     .sync_labels(&desired)
+    ///     .repo("owner/repo")
+    ///     .delete_missing()
+    ///     .execute();
+    /// ```
+    pub fn sync_labels(&self, desired: &[Label]) -> LabelSyncCommand {
+        LabelSyncCommand::new(self.executor.clone(), desired)
+    }
+}
+
+/// Normalize a hex color ("#D73A4A" or "d73a4a") to gh's form ("d73a4a")
+fn normalize_color(color: &str) -> Option<String> {
+    let hex = color.strip_prefix('#').unwrap_or(color);
+    if hex.len() == 6 && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        Some(hex.to_ascii_lowercase())
+    } else {
+        None
+    }
+}
+
+fn invalid_color(color: &Option<String>) -> Result<()> {
+    match color {
+        Some(color) => Err(GhError::InvalidCommand(format!(
+            "label color must be a 6 digit hex value, got {:?}",
+            color
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Command for listing labels
+pub struct LabelListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl LabelListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["label", "list"]),
+        }
+    }
+
+    /// List labels of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Only labels whose name or description matches
+    pub fn search(mut self, query: &str) -> Self {
+        self.cmd = self.cmd.option("--search", query);
+        self
+    }
+
+    /// Limit the number of results (gh defaults to 30)
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the labels
+    pub fn fetch(&self) -> Result<Vec<Label>> {
+        let cmd = self.cmd.clone().option("--json", "name,color,description");
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for LabelListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for creating a label
+pub struct LabelCreateCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    invalid_color: Option<String>,
+}
+
+impl LabelCreateCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["label", "create"]).arg(name),
+            invalid_color: None,
+        }
+    }
+
+    /// Set the color as a hex value such as "d73a4a" or "#D73A4A"
+    ///
+    /// Invalid colors are reported as `GhError::InvalidCommand` on execute.
+    pub fn color(mut self, color: &str) -> Self {
+        match normalize_color(color) {
+            Some(color) => self.cmd = self.cmd.option("--color", &color),
+            None => self.invalid_color = Some(color.to_string()),
+        }
+        self
+    }
+
+    /// Set the description
+    pub fn description(mut self, desc: &str) -> Self {
+        self.cmd = self.cmd.option("--description", desc);
+        self
+    }
+
+    /// Update the label if it already exists
+    pub fn force(mut self) -> Self {
+        self.cmd = self.cmd.flag("--force");
+        self
+    }
+
+    /// Create the label in the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute the create command
+    pub fn execute(&self) -> Result<String> {
+        invalid_color(&self.invalid_color)?;
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for LabelCreateCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for editing a label
+pub struct LabelEditCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    invalid_color: Option<String>,
+}
+
+impl LabelEditCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["label", "edit"]).arg(name),
+            invalid_color: None,
+        }
+    }
+
+    /// Rename the label
+    pub fn rename(mut self, new_name: &str) -> Self {
+        self.cmd = self.cmd.option("--name", new_name);
+        self
+    }
+
+    /// Set the color as a hex value such as "d73a4a" or "#D73A4A"
+    ///
+    /// Invalid colors are reported as `GhError::InvalidCommand` on execute.
+    pub fn color(mut self, color: &str) -> Self {
+        match normalize_color(color) {
+            Some(color) => self.cmd = self.cmd.option("--color", &color),
+            None => self.invalid_color = Some(color.to_string()),
+        }
+        self
+    }
+
+    /// Set the description
+    pub fn description(mut self, desc: &str) -> Self {
+        self.cmd = self.cmd.option("--description", desc);
+        self
+    }
+
+    /// Edit the label in the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute the edit command
+    pub fn execute(&self) -> Result<String> {
+        invalid_color(&self.invalid_color)?;
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for LabelEditCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for deleting a label
+pub struct LabelDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl LabelDeleteCommand {
+    fn new(executor: Arc<GhExecutor>, name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["label", "delete"]).arg(name),
+        }
+    }
+
+    /// Confirm deletion without prompting (required when not interactive)
+    pub fn yes(mut self) -> Self {
+        self.cmd = self.cmd.flag("--yes");
+        self
+    }
+
+    /// Delete the label from the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute the delete command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for LabelDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for cloning labels from another repository
+pub struct LabelCloneCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl LabelCloneCommand {
+    fn new(executor: Arc<GhExecutor>, source: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["label", "clone"]).arg(source),
+        }
+    }
+
+    /// Overwrite existing labels with the same name
+    pub fn force(mut self) -> Self {
+        self.cmd = self.cmd.flag("--force");
+        self
+    }
+
+    /// Clone into the given repository instead of the current one
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Execute the clone command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for LabelCloneCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Changes needed to make a repository's labels match a desired set
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LabelDiff {
+    /// Labels that do not exist yet
+    pub create: Vec<Label>,
+    /// Existing labels whose color or description differs
+    pub update: Vec<Label>,
+    /// Names of existing labels not in the desired set (only with `delete_missing`)
+    pub delete: Vec<String>,
+}
+
+impl LabelDiff {
+    /// Compute the diff; names are compared case-insensitively, like GitHub does
+    pub fn between(current: &[Label], desired: &[Label], delete_missing: bool) -> Self {
+        let find = |labels: &[Label], name: &str| {
+            labels
+                .iter()
+                .position(|l| l.name.eq_ignore_ascii_case(name))
+        };
+
+        let mut diff = LabelDiff::default();
+        for label in desired {
+            let color = normalize_color(&label.color).unwrap_or_else(|| label.color.clone());
+            match find(current, &label.name) {
+                None => diff.create.push(label.clone()),
+                Some(i) => {
+                    let existing = &current[i];
+                    if !existing.color.eq_ignore_ascii_case(&color)
+                        || existing.description != label.description
+                        || existing.name != label.name
+                    {
+                        diff.update.push(label.clone());
+                    }
+                }
+            }
+        }
+
+        if delete_missing {
+            diff.delete = current
+                .iter()
+                .filter(|l| find(desired, &l.name).is_none())
+                .map(|l| l.name.clone())
+                .collect();
+        }
+        diff
+    }
+
+    /// Check whether no changes are needed
+    pub fn is_empty(&self) -> bool {
+        self.create.is_empty() && self.update.is_empty() && self.delete.is_empty()
+    }
+}
+
+/// Outcome of `LabelSyncCommand::execute`
+#[derive(Debug)]
+pub struct LabelSyncReport {
+    /// Changes that were applied
+    pub applied: LabelDiff,
+    /// Failure that stopped the sync before all changes were applied
+    pub error: Option<GhError>,
+}
+
+impl LabelSyncReport {
+    /// The applied changes, or the error if the sync stopped early
+    pub fn into_result(self) -> Result<LabelDiff> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.applied),
+        }
+    }
+}
+
+/// Most labels `LabelSyncCommand` reads from a repository
+const SYNC_LIST_LIMIT: u32 = 10_000;
+
+/// Command for syncing labels to a desired set
+pub struct LabelSyncCommand {
+    executor: Arc<GhExecutor>,
+    desired: Vec<Label>,
+    repo: Option<String>,
+    delete_missing: bool,
+}
+
+impl LabelSyncCommand {
+    fn new(executor: Arc<GhExecutor>, desired: &[Label]) -> Self {
+        Self {
+            executor,
+            desired: desired.to_vec(),
+            repo: None,
+            delete_missing: false,
+        }
+    }
+
+    /// Sync labels of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.repo = Some(repo.to_string());
+        self
+    }
+
+    /// Also delete labels that are not in the desired set
+    pub fn delete_missing(mut self) -> Self {
+        self.delete_missing = true;
+        self
+    }
+
+    /// Compute the changes without applying them
+    ///
+    /// Fails if any desired color is invalid, or if the repository has more
+    /// than 10,000 labels.
+    pub fn plan(&self) -> Result<LabelDiff> {
+        let invalid: Vec<String> = self
+            .desired
+            .iter()
+            .filter(|label| normalize_color(&label.color).is_none())
+            .map(|label| format!("{} ({:?})", label.name, label.color))
+            .collect();
+        if !invalid.is_empty() {
+            return Err(GhError::InvalidCommand(format!(
+                "label colors must be 6 digit hex values: {}",
+                invalid.join(", ")
+            )));
+        }
+
+        let mut list = LabelListCommand::new(self.executor.clone()).limit(SYNC_LIST_LIMIT);
+        if let Some(repo) = &self.repo {
+            list = list.repo(repo);
+        }
+        let current = list.fetch()?;
+        if current.len() >= SYNC_LIST_LIMIT as usize {
+            return Err(GhError::InvalidCommand(format!(
+                "cannot sync repositories with {} or more labels",
+                SYNC_LIST_LIMIT
+            )));
+        }
+        Ok(LabelDiff::between(
+            &current,
+            &self.desired,
+            self.delete_missing,
+        ))
+    }
+
+    /// Compute and apply the changes
+    ///
+    /// Returns `Err` only if nothing was changed. A failure while applying is
+    /// reported in `LabelSyncReport::error` along with the changes made so far.
+    pub fn execute(&self) -> Result<LabelSyncReport> {
+        let diff = self.plan()?;
+        let mut report = LabelSyncReport {
+            applied: LabelDiff::default(),
+            error: None,
+        };
+        if let Err(error) = self.apply(&diff, &mut report.applied) {
+            report.error = Some(error);
+        }
+        Ok(report)
+    }
+
+    /// Apply the diff, recording each change in `applied` once it succeeded
+    fn apply(&self, diff: &LabelDiff, applied: &mut LabelDiff) -> Result<()> {
+        let repo = self.repo.as_deref();
+
+        for label in &diff.create {
+            let mut cmd = LabelCreateCommand::new(self.executor.clone(), &label.name)
+                .color(&label.color)
+                .description(&label.description);
+            if let Some(repo) = repo {
+                cmd = cmd.repo(repo);
+            }
+            cmd.execute()?;
+            applied.create.push(label.clone());
+        }
+
+        for label in &diff.update {
+            // Renaming to the desired name fixes differences in case
+            let mut cmd = LabelEditCommand::new(self.executor.clone(), &label.name)
+                .rename(&label.name)
+                .color(&label.color)
+                .description(&label.description);
+            if let Some(repo) = repo {
+                cmd = cmd.repo(repo);
+            }
+            cmd.execute()?;
+            applied.update.push(label.clone());
+        }
+
+        for name in &diff.delete {
+            let mut cmd = LabelDeleteCommand::new(self.executor.clone(), name).yes();
+            if let Some(repo) = repo {
+                cmd = cmd.repo(repo);
+            }
+            cmd.execute()?;
+            applied.delete.push(name.clone());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    fn interaction(args: &[&str], stdout: &str, exit_code: i32) -> Interaction {
+        Interaction {
+            args: args.iter().map(|s| s.to_string()).collect(),
+            env: BTreeMap::new(),
+            stdin: None,
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            exit_code,
+        }
+    }
+
+    fn replaying(interactions: Vec<Interaction>) -> Arc<GhExecutor> {
+        Arc::new(GhExecutor::default().with_cassette(Cassette::from_interactions(interactions)))
+    }
+
+    fn label(name: &str, color: &str, description: &str) -> Label {
+        Label {
+            name: name.to_string(),
+            color: color.to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_color() {
+        assert_eq!(normalize_color("#D73A4A").as_deref(), Some("d73a4a"));
+        assert_eq!(normalize_color("0e8a16").as_deref(), Some("0e8a16"));
+        assert_eq!(normalize_color("red"), None);
+        assert_eq!(normalize_color("#12345"), None);
+    }
+
+    #[test]
+    fn test_label_diff() {
+        let current = vec![
+            label("bug", "d73a4a", "Something isn't working"),
+            label("Docs", "0075ca", ""),
+            label("wontfix", "ffffff", ""),
+        ];
+        let desired = vec![
+            label("bug", "#D73A4A", "Something isn't working"),
+            label("docs", "0075ca", ""),
+            label("triage", "fbca04", "Needs triage"),
+        ];
+
+        let diff = LabelDiff::between(&current, &desired, true);
+        assert_eq!(diff.create, vec![desired[2].clone()]);
+        assert_eq!(diff.update, vec![desired[1].clone()]);
+        assert_eq!(diff.delete, vec!["wontfix".to_string()]);
+
+        assert!(LabelDiff::between(&current, &current, true).is_empty());
+        assert!(LabelDiff::between(&current, &desired, false)
+            .delete
+            .is_empty());
+    }
+
+    #[test]
+    fn test_sync_validates_colors_before_running_gh() {
+        // No recorded interactions: any gh invocation would fail as unexpected
+        let desired = vec![label("bug", "d73a4a", ""), label("docs", "", "")];
+        let sync = LabelSyncCommand::new(replaying(Vec::new()), &desired);
+        match sync.execute() {
+            Err(GhError::InvalidCommand(message)) => assert!(message.contains("docs")),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sync_reports_partial_progress() {
+        let list = ["label", "list", "--limit", "10000"];
+        let list = [&list[..], &["--json", "name,color,description"]].concat();
+        let create = |name| {
+            [
+                "label",
+                "create",
+                name,
+                "--color",
+                "d73a4a",
+                "--description",
+                "",
+            ]
+        };
+        let executor = replaying(vec![
+            interaction(&list, "[]", 0),
+            interaction(&create("a"), "", 0),
+            interaction(&create("b"), "", 1),
+        ]);
+        let desired = vec![label("a", "d73a4a", ""), label("b", "d73a4a", "")];
+
+        let report = LabelSyncCommand::new(executor, &desired).execute().unwrap();
+        assert_eq!(report.applied.create, vec![desired[0].clone()]);
+        assert!(matches!(
+            report.error,
+            Some(GhError::CommandFailed { code: 1, .. })
+        ));
+    }
+}
@@ -1,8 +1,48 @@
+pub mod alias;
+pub mod api;
+pub mod attestation;
+pub mod autolink;
+pub mod browse;
+pub mod cache;
+pub mod codespace;
+pub mod config;
+pub mod deploy_key;
+pub mod extension;
+pub mod gist;
+pub mod gpg_key;
 pub mod issue;
+pub mod label;
+pub mod org;
 pub mod pr;
+pub mod project;
 pub mod repo;
+pub mod ruleset;
+pub mod search;
+pub mod ssh_key;
+pub mod status;
+
+mod key_input;
 
 // Re-export command types
+pub use alias::*;
+pub use api::*;
+pub use attestation::*;
+pub use autolink::*;
+pub use browse::*;
+pub use cache::*;
+pub use codespace::*;
+pub use config::*;
+pub use deploy_key::*;
+pub use extension::*;
+pub use gist::*;
+pub use gpg_key::*;
 pub use issue::*;
+pub use label::*;
+pub use org::*;
 pub use pr::*;
+pub use project::*;
 pub use repo::*;
+pub use ruleset::*;
+pub use search::*;
+pub use ssh_key::*;
+pub use status::*;
@@ -0,0 +1,497 @@
+use crate::command::GhCommand;
+use crate::commands::api::paginated;
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Largest page the REST API serves
+const PAGE_SIZE: usize = 100;
+
+/// Organization commands namespace
+///
+/// Organizations, members, teams and outside collaborators are read from the
+/// REST API with `gh api --paginate` (`gh org list` has no JSON output).
+#[derive(Clone)]
+pub struct OrgCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl OrgCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List organizations of the authenticated user
+    pub fn list(&self) -> OrgListCommand {
+        OrgListCommand::new(self.executor.clone())
+    }
+
+    /// List members of an organization
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::{GhClient, OrgRole};
+    /// let client = GhClient::new();
+    /// let admins = client.org().members("octo").role(OrgRole::Admin).fetch();
+    /// ```
+    pub fn members(&self, org: &str) -> OrgMembersCommand {
+        OrgMembersCommand::new(self.executor.clone(), org)
+    }
+
+    /// List teams of an organization
+    pub fn teams(&self, org: &str) -> OrgTeamsCommand {
+        OrgTeamsCommand::new(self.executor.clone(), org)
+    }
+
+    /// List members of a team
+    pub fn team_members(&self, org: &str, team_slug: &str) -> TeamMembersCommand {
+        TeamMembersCommand::new(self.executor.clone(), org, team_slug)
+    }
+
+    /// List outside collaborators of an organization
+    pub fn outside_collaborators(&self, org: &str) -> OutsideCollaboratorsCommand {
+        OutsideCollaboratorsCommand::new(self.executor.clone(), org)
+    }
+}
+
+/// Role filter for organization members
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrgRole {
+    All,
+    Admin,
+    Member,
+}
+
+impl OrgRole {
+    fn as_str(&self) -> &'static str {
+        match self {
+            OrgRole::All => "all",
+            OrgRole::Admin => "admin",
+            OrgRole::Member => "member",
+        }
+    }
+}
+
+/// Role filter for team members
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TeamRole {
+    All,
+    Maintainer,
+    Member,
+}
+
+impl TeamRole {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TeamRole::All => "all",
+            TeamRole::Maintainer => "maintainer",
+            TeamRole::Member => "member",
+        }
+    }
+}
+
+/// An organization
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Organization {
+    pub id: u64,
+    pub login: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// A user returned by the member and collaborator listings
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct OrgUser {
+    pub id: u64,
+    pub login: String,
+    /// `User` or `Bot`
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub site_admin: bool,
+    #[serde(default)]
+    pub html_url: String,
+}
+
+/// Parent of a nested team
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TeamParent {
+    pub id: u64,
+    pub slug: String,
+    pub name: String,
+}
+
+/// Team visibility
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TeamPrivacy {
+    /// Visible to all organization members
+    Closed,
+    /// Visible only to its members and organization owners
+    Secret,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A team
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Team {
+    pub id: u64,
+    pub slug: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub privacy: TeamPrivacy,
+    /// Default repository permission (e.g. `pull`, `push`)
+    #[serde(default)]
+    pub permission: String,
+    #[serde(default)]
+    pub parent: Option<TeamParent>,
+    #[serde(default)]
+    pub html_url: String,
+}
+
+/// Command for listing organizations
+pub struct OrgListCommand {
+    executor: Arc<GhExecutor>,
+    limit: Option<u32>,
+}
+
+impl OrgListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            limit: None,
+        }
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Execute and get raw JSON output (every page, unless the limit fits on one page)
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the organizations, requesting only the pages needed for the limit
+    pub fn fetch(&self) -> Result<Vec<Organization>> {
+        let limit = match self.limit {
+            Some(limit) => limit as usize,
+            None => return self.executor.execute_json_pages(&self.build_args()),
+        };
+        let per_page = limit.min(PAGE_SIZE);
+        let mut orgs = Vec::new();
+        let mut page = 1;
+        while orgs.len() < limit {
+            let batch: Vec<Organization> =
+                self.executor.execute_json(&orgs_page(per_page, page))?;
+            let last = batch.len() < per_page;
+            orgs.extend(batch);
+            if last {
+                break;
+            }
+            page += 1;
+        }
+        orgs.truncate(limit);
+        Ok(orgs)
+    }
+}
+
+/// Arguments for a single page of `user/orgs`
+fn orgs_page(per_page: usize, page: u32) -> Vec<String> {
+    vec![
+        "api".to_string(),
+        format!("user/orgs?per_page={}&page={}", per_page, page),
+    ]
+}
+
+impl GhCommand for OrgListCommand {
+    fn build_args(&self) -> Vec<String> {
+        match self.limit {
+            Some(limit) if (limit as usize) <= PAGE_SIZE => orgs_page(limit as usize, 1),
+            _ => paginated("user/orgs", &[]),
+        }
+    }
+}
+
+/// Command for listing organization members
+pub struct OrgMembersCommand {
+    executor: Arc<GhExecutor>,
+    org: String,
+    role: OrgRole,
+    two_factor_disabled: bool,
+}
+
+impl OrgMembersCommand {
+    fn new(executor: Arc<GhExecutor>, org: &str) -> Self {
+        Self {
+            executor,
+            org: org.to_string(),
+            role: OrgRole::All,
+            two_factor_disabled: false,
+        }
+    }
+
+    /// Only members with the given role
+    pub fn role(mut self, role: OrgRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Only members without two-factor authentication (requires owner access)
+    pub fn two_factor_disabled(mut self) -> Self {
+        self.two_factor_disabled = true;
+        self
+    }
+
+    /// Execute and get raw JSON output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the members
+    pub fn fetch(&self) -> Result<Vec<OrgUser>> {
+        self.executor.execute_json_pages(&self.build_args())
+    }
+}
+
+impl GhCommand for OrgMembersCommand {
+    fn build_args(&self) -> Vec<String> {
+        let filter = if self.two_factor_disabled {
+            "2fa_disabled"
+        } else {
+            "all"
+        };
+        paginated(
+            &format!("orgs/{}/members", self.org),
+            &[("role", self.role.as_str()), ("filter", filter)],
+        )
+    }
+}
+
+/// Command for listing organization teams
+pub struct OrgTeamsCommand {
+    executor: Arc<GhExecutor>,
+    org: String,
+}
+
+impl OrgTeamsCommand {
+    fn new(executor: Arc<GhExecutor>, org: &str) -> Self {
+        Self {
+            executor,
+            org: org.to_string(),
+        }
+    }
+
+    /// Execute and get raw JSON output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the teams
+    pub fn fetch(&self) -> Result<Vec<Team>> {
+        self.executor.execute_json_pages(&self.build_args())
+    }
+}
+
+impl GhCommand for OrgTeamsCommand {
+    fn build_args(&self) -> Vec<String> {
+        paginated(&format!("orgs/{}/teams", self.org), &[])
+    }
+}
+
+/// Command for listing team members
+pub struct TeamMembersCommand {
+    executor: Arc<GhExecutor>,
+    org: String,
+    team_slug: String,
+    role: TeamRole,
+}
+
+impl TeamMembersCommand {
+    fn new(executor: Arc<GhExecutor>, org: &str, team_slug: &str) -> Self {
+        Self {
+            executor,
+            org: org.to_string(),
+            team_slug: team_slug.to_string(),
+            role: TeamRole::All,
+        }
+    }
+
+    /// Only members with the given role
+    pub fn role(mut self, role: TeamRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Execute and get raw JSON output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the members (including members of child teams)
+    pub fn fetch(&self) -> Result<Vec<OrgUser>> {
+        self.executor.execute_json_pages(&self.build_args())
+    }
+}
+
+impl GhCommand for TeamMembersCommand {
+    fn build_args(&self) -> Vec<String> {
+        paginated(
+            &format!("orgs/{}/teams/{}/members", self.org, self.team_slug),
+            &[("role", self.role.as_str())],
+        )
+    }
+}
+
+/// Command for listing outside collaborators
+pub struct OutsideCollaboratorsCommand {
+    executor: Arc<GhExecutor>,
+    org: String,
+    two_factor_disabled: bool,
+}
+
+impl OutsideCollaboratorsCommand {
+    fn new(executor: Arc<GhExecutor>, org: &str) -> Self {
+        Self {
+            executor,
+            org: org.to_string(),
+            two_factor_disabled: false,
+        }
+    }
+
+    /// Only collaborators without two-factor authentication
+    pub fn two_factor_disabled(mut self) -> Self {
+        self.two_factor_disabled = true;
+        self
+    }
+
+    /// Execute and get raw JSON output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the collaborators
+    pub fn fetch(&self) -> Result<Vec<OrgUser>> {
+        self.executor.execute_json_pages(&self.build_args())
+    }
+}
+
+impl GhCommand for OutsideCollaboratorsCommand {
+    fn build_args(&self) -> Vec<String> {
+        let filter = if self.two_factor_disabled {
+            "2fa_disabled"
+        } else {
+            "all"
+        };
+        paginated(
+            &format!("orgs/{}/outside_collaborators", self.org),
+            &[("filter", filter)],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    fn interaction(args: &[String], stdout: String) -> Interaction {
+        Interaction {
+            args: args.to_vec(),
+            env: BTreeMap::new(),
+            stdin: None,
+            stdout,
+            stderr: String::new(),
+            exit_code: 0,
+        }
+    }
+
+    fn orgs(ids: std::ops::Range<u64>) -> String {
+        let orgs: Vec<String> = ids
+            .map(|id| format!(r#"{{"id": {}, "login": "org{}"}}"#, id, id))
+            .collect();
+        format!("[{}]", orgs.join(","))
+    }
+
+    #[test]
+    fn test_paginated_args() {
+        let executor = Arc::new(GhExecutor::default());
+        let members = OrgMembersCommand::new(executor, "octo").role(OrgRole::Admin);
+        assert_eq!(
+            members.build_args(),
+            vec![
+                "api",
+                "--paginate",
+                "orgs/octo/members?per_page=100&role=admin&filter=all"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_list_stops_at_the_limit() {
+        let executor = GhExecutor::default().with_cassette(Cassette::from_interactions(vec![
+            interaction(&orgs_page(100, 1), orgs(0..100)),
+            interaction(&orgs_page(100, 2), orgs(100..200)),
+            interaction(&orgs_page(5, 1), orgs(0..5)),
+        ]));
+        let executor = Arc::new(executor);
+
+        let list = OrgListCommand::new(executor.clone()).limit(150);
+        let found = list.fetch().unwrap();
+        assert_eq!(found.len(), 150);
+        assert_eq!(found[149].login, "org149");
+
+        let list = OrgListCommand::new(executor.clone()).limit(5);
+        assert_eq!(
+            list.build_args(),
+            vec!["api", "user/orgs?per_page=5&page=1"]
+        );
+        assert_eq!(list.fetch().unwrap().len(), 5);
+
+        assert_eq!(
+            OrgListCommand::new(executor).build_args(),
+            vec!["api", "--paginate", "user/orgs?per_page=100"]
+        );
+    }
+
+    #[test]
+    fn test_team_decoding() {
+        let json = r#"[
+            {"id": 1, "node_id": "T_1", "slug": "core", "name": "Core",
+             "description": "Maintainers", "privacy": "closed", "permission": "push",
+             "parent": null, "html_url": "https://github.com/orgs/octo/teams/core"},
+            {"id": 2, "slug": "security", "name": "Security", "description": null,
+             "privacy": "secret", "parent": {"id": 1, "slug": "core", "name": "Core"}},
+            {"id": 3, "slug": "new", "name": "New", "privacy": "visible"}
+        ]"#;
+        let teams: Vec<Team> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(teams[0].privacy, TeamPrivacy::Closed);
+        assert_eq!(teams[0].description.as_deref(), Some("Maintainers"));
+        assert_eq!(teams[0].parent, None);
+        assert_eq!(teams[1].privacy, TeamPrivacy::Secret);
+        assert_eq!(teams[1].parent.as_ref().unwrap().slug, "core");
+        assert_eq!(teams[1].permission, "");
+        assert_eq!(teams[2].privacy, TeamPrivacy::Unknown);
+    }
+
+    #[test]
+    fn test_user_decoding() {
+        let json = r#"[
+            {"login": "octocat", "id": 1, "type": "User", "site_admin": false,
+             "html_url": "https://github.com/octocat"},
+            {"login": "dependabot[bot]", "id": 2, "type": "Bot"}
+        ]"#;
+        let users: Vec<OrgUser> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(users[0].kind, "User");
+        assert_eq!(users[0].html_url, "https://github.com/octocat");
+        assert_eq!(users[1].kind, "Bot");
+        assert!(!users[1].site_admin);
+    }
+}
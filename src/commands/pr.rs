@@ -1,6 +1,11 @@
 use crate::command::{BaseCommand, CommandBuilder, GhCommand};
-use crate::error::Result;
+use crate::commands::api::percent_encode;
+use crate::commands::browse::{query_string, repo_url};
+use crate::error::{GhError, Result};
 use crate::executor::GhExecutor;
+use crate::selector::PrSelector;
+use crate::types::{Actor, Comment, Label, Timestamp};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 /// Pull request commands namespace
@@ -24,24 +29,24 @@ impl PrCommands {
         PrListCommand::new(self.executor.clone())
     }
 
-    /// View a pull request
-    pub fn view(&self, number: u32) -> PrViewCommand {
-        PrViewCommand::new(self.executor.clone(), number)
+    /// View a pull request by number, URL or branch
+    pub fn view(&self, pr: impl Into<PrSelector>) -> PrViewCommand {
+        PrViewCommand::new(self.executor.clone(), pr.into())
     }
 
-    /// Checkout a pull request
-    pub fn checkout(&self, number: u32) -> PrCheckoutCommand {
-        PrCheckoutCommand::new(self.executor.clone(), number)
+    /// Checkout a pull request by number, URL or branch
+    pub fn checkout(&self, pr: impl Into<PrSelector>) -> PrCheckoutCommand {
+        PrCheckoutCommand::new(self.executor.clone(), pr.into())
     }
 
-    /// Merge a pull request
-    pub fn merge(&self, number: u32) -> PrMergeCommand {
-        PrMergeCommand::new(self.executor.clone(), number)
+    /// Merge a pull request by number, URL or branch
+    pub fn merge(&self, pr: impl Into<PrSelector>) -> PrMergeCommand {
+        PrMergeCommand::new(self.executor.clone(), pr.into())
     }
 
-    /// Close a pull request
-    pub fn close(&self, number: u32) -> PrCloseCommand {
-        PrCloseCommand::new(self.executor.clone(), number)
+    /// Close a pull request by number, URL or branch
+    pub fn close(&self, pr: impl Into<PrSelector>) -> PrCloseCommand {
+        PrCloseCommand::new(self.executor.clone(), pr.into())
     }
 }
 
@@ -99,6 +104,53 @@ impl PrCreateCommand {
     pub fn execute(&self) -> Result<String> {
         GhCommand::execute(self, self.executor.as_ref())
     }
+
+    /// Get the URL of the prefilled form `.web()` opens, without creating the pull request
+    ///
+    /// Requires `.head()`, since gh would otherwise use the checked out branch.
+    /// Without `.base()` the repository default branch is compared against.
+    /// Fails with `.draft()`, which the web form cannot prefill.
+    pub fn url(&self) -> Result<String> {
+        if self.cmd.args.iter().any(|a| a == "--draft") {
+            return Err(GhError::InvalidCommand(
+                "pr create url cannot open a draft pull request".to_string(),
+            ));
+        }
+        let head = self.cmd.values("--head").pop().ok_or_else(|| {
+            GhError::InvalidCommand("pr create url requires a head branch".to_string())
+        })?;
+        let base = match self.cmd.values("--base").pop() {
+            Some(base) => base.to_string(),
+            None => {
+                let cmd = BaseCommand::with_subcommands(&["repo", "view"])
+                    .option("--json", "defaultBranchRef")
+                    .option("--jq", ".defaultBranchRef.name");
+                GhCommand::execute(&cmd, self.executor.as_ref())?
+                    .trim()
+                    .to_string()
+            }
+        };
+        let query = query_string(&[
+            ("expand", "1".to_string()),
+            ("title", self.cmd.values("--title").join("")),
+            ("body", self.cmd.values("--body").join("")),
+        ]);
+        Ok(format!(
+            "{}/compare/{}...{}{}",
+            repo_url(&self.executor)?,
+            compare_ref(&base),
+            compare_ref(head),
+            query
+        ))
+    }
+}
+
+/// Percent-encode a branch for a compare URL, keeping `owner:branch` and
+/// `/` in branch names readable as GitHub does
+fn compare_ref(branch: &str) -> String {
+    percent_encode(branch)
+        .replace("%2F", "/")
+        .replace("%3A", ":")
 }
 
 impl GhCommand for PrCreateCommand {
@@ -151,6 +203,224 @@ impl GhCommand for PrListCommand {
     }
 }
 
+/// Fields requested by `PrViewCommand::fetch`
+const PR_DETAIL_FIELDS: &[&str] = &[
+    "number",
+    "title",
+    "body",
+    "state",
+    "url",
+    "isDraft",
+    "author",
+    "baseRefName",
+    "headRefName",
+    "labels",
+    "assignees",
+    "reviewRequests",
+    "reviews",
+    "comments",
+    "files",
+    "commits",
+    "additions",
+    "deletions",
+    "mergeable",
+    "mergeStateStatus",
+    "statusCheckRollup",
+    "createdAt",
+    "updatedAt",
+    "closedAt",
+    "mergedAt",
+];
+
+/// State of a pull request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PrState {
+    Open,
+    Closed,
+    Merged,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether a pull request can be merged without conflicts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Mergeable {
+    Mergeable,
+    Conflicting,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Detailed merge state of a pull request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MergeStateStatus {
+    Behind,
+    Blocked,
+    Clean,
+    Dirty,
+    Draft,
+    HasHooks,
+    Unstable,
+    #[serde(other)]
+    Unknown,
+}
+
+/// State of a pull request review
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewState {
+    Approved,
+    ChangesRequested,
+    Commented,
+    Dismissed,
+    Pending,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A user or team whose review was requested
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+pub enum ReviewRequest {
+    User {
+        login: String,
+    },
+    Team {
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        slug: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// A submitted pull request review
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Review {
+    pub author: Actor,
+    pub state: ReviewState,
+    #[serde(default)]
+    pub body: String,
+    #[serde(default)]
+    pub submitted_at: Option<Timestamp>,
+}
+
+/// A file changed by a pull request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrFile {
+    pub path: String,
+    pub additions: u64,
+    pub deletions: u64,
+}
+
+/// Author of a commit as recorded by git
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitAuthor {
+    #[serde(default)]
+    pub login: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub email: String,
+}
+
+/// A commit in a pull request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrCommit {
+    pub oid: String,
+    pub message_headline: String,
+    #[serde(default)]
+    pub message_body: String,
+    #[serde(default)]
+    pub authors: Vec<CommitAuthor>,
+    #[serde(default)]
+    pub authored_date: Option<Timestamp>,
+    #[serde(default)]
+    pub committed_date: Option<Timestamp>,
+}
+
+/// A check run or commit status reported on a pull request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+pub enum StatusCheck {
+    #[serde(rename_all = "camelCase")]
+    CheckRun {
+        name: String,
+        /// QUEUED, IN_PROGRESS, COMPLETED, ...
+        #[serde(default)]
+        status: String,
+        /// SUCCESS, FAILURE, NEUTRAL, SKIPPED, ... (empty while running)
+        #[serde(default)]
+        conclusion: String,
+        #[serde(default)]
+        workflow_name: String,
+        #[serde(default)]
+        details_url: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    StatusContext {
+        context: String,
+        /// SUCCESS, FAILURE, PENDING, ERROR, ...
+        #[serde(default)]
+        state: String,
+        #[serde(default)]
+        target_url: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// Pull request details returned by `PrViewCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestDetail {
+    pub number: u32,
+    pub title: String,
+    #[serde(default)]
+    pub body: String,
+    pub state: PrState,
+    pub url: String,
+    #[serde(default)]
+    pub is_draft: bool,
+    pub author: Actor,
+    pub base_ref_name: String,
+    pub head_ref_name: String,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub assignees: Vec<Actor>,
+    #[serde(default)]
+    pub review_requests: Vec<ReviewRequest>,
+    #[serde(default)]
+    pub reviews: Vec<Review>,
+    #[serde(default)]
+    pub comments: Vec<Comment>,
+    #[serde(default)]
+    pub files: Vec<PrFile>,
+    #[serde(default)]
+    pub commits: Vec<PrCommit>,
+    #[serde(default)]
+    pub additions: u64,
+    #[serde(default)]
+    pub deletions: u64,
+    pub mergeable: Mergeable,
+    pub merge_state_status: MergeStateStatus,
+    #[serde(default)]
+    pub status_check_rollup: Vec<StatusCheck>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    #[serde(default)]
+    pub closed_at: Option<Timestamp>,
+    #[serde(default)]
+    pub merged_at: Option<Timestamp>,
+}
+
 /// Command for viewing a pull request
 pub struct PrViewCommand {
     executor: Arc<GhExecutor>,
@@ -158,10 +428,10 @@ pub struct PrViewCommand {
 }
 
 impl PrViewCommand {
-    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+    fn new(executor: Arc<GhExecutor>, pr: PrSelector) -> Self {
         Self {
             executor,
-            cmd: BaseCommand::with_subcommands(&["pr", "view"]).arg(&number.to_string()),
+            cmd: pr.apply(BaseCommand::with_subcommands(&["pr", "view"])),
         }
     }
 
@@ -175,6 +445,27 @@ impl PrViewCommand {
     pub fn execute(&self) -> Result<String> {
         GhCommand::execute(self, self.executor.as_ref())
     }
+
+    /// Execute and parse the pull request details, including comments and reviews
+    pub fn fetch(&self) -> Result<PullRequestDetail> {
+        let cmd = self
+            .cmd
+            .without_flag("--web")
+            .option("--json", &PR_DETAIL_FIELDS.join(","));
+        self.executor.execute_json(&cmd.build_args())
+    }
+
+    /// Get the web URL without opening a browser
+    pub fn url(&self) -> Result<String> {
+        let cmd = self
+            .cmd
+            .without_flag("--web")
+            .option("--json", "url")
+            .option("--jq", ".url");
+        Ok(GhCommand::execute(&cmd, self.executor.as_ref())?
+            .trim()
+            .to_string())
+    }
 }
 
 impl GhCommand for PrViewCommand {
@@ -190,10 +481,10 @@ pub struct PrCheckoutCommand {
 }
 
 impl PrCheckoutCommand {
-    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+    fn new(executor: Arc<GhExecutor>, pr: PrSelector) -> Self {
         Self {
             executor,
-            cmd: BaseCommand::with_subcommands(&["pr", "checkout"]).arg(&number.to_string()),
+            cmd: pr.apply(BaseCommand::with_subcommands(&["pr", "checkout"])),
         }
     }
 
@@ -216,10 +507,10 @@ pub struct PrMergeCommand {
 }
 
 impl PrMergeCommand {
-    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+    fn new(executor: Arc<GhExecutor>, pr: PrSelector) -> Self {
         Self {
             executor,
-            cmd: BaseCommand::with_subcommands(&["pr", "merge"]).arg(&number.to_string()),
+            cmd: pr.apply(BaseCommand::with_subcommands(&["pr", "merge"])),
         }
     }
 
@@ -266,10 +557,10 @@ pub struct PrCloseCommand {
 }
 
 impl PrCloseCommand {
-    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+    fn new(executor: Arc<GhExecutor>, pr: PrSelector) -> Self {
         Self {
             executor,
-            cmd: BaseCommand::with_subcommands(&["pr", "close"]).arg(&number.to_string()),
+            cmd: pr.apply(BaseCommand::with_subcommands(&["pr", "close"])),
         }
     }
 
@@ -290,3 +581,118 @@ impl GhCommand for PrCloseCommand {
         self.cmd.build_args()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    const DETAIL: &str = r#"{
+        "number": 7, "title": "Fix crash", "body": "", "state": "OPEN",
+        "url": "https://github.com/octo/app/pull/7", "isDraft": true,
+        "author": {"login": "octocat", "is_bot": false},
+        "baseRefName": "main", "headRefName": "fix/crash",
+        "labels": [{"name": "bug", "color": "d73a4a", "description": ""}],
+        "reviewRequests": [
+            {"__typename": "User", "login": "hubot"},
+            {"__typename": "Team", "name": "Core", "slug": "core"},
+            {"__typename": "Mannequin", "login": "ghost"}
+        ],
+        "reviews": [
+            {"author": {"login": "hubot"}, "state": "APPROVED", "body": "",
+             "submittedAt": "2024-01-02T00:00:00Z"},
+            {"author": {"login": "monalisa"}, "state": "SOMETHING_NEW"}
+        ],
+        "files": [{"path": "src/main.rs", "additions": 3, "deletions": 1}],
+        "commits": [{"oid": "abc123", "messageHeadline": "Fix crash",
+                     "authors": [{"login": "octocat", "name": "Octo Cat", "email": ""}]}],
+        "additions": 3, "deletions": 1,
+        "mergeable": "UNKNOWN", "mergeStateStatus": "QUEUED",
+        "statusCheckRollup": [
+            {"__typename": "CheckRun", "name": "test", "status": "COMPLETED",
+             "conclusion": "SUCCESS", "workflowName": "CI",
+             "detailsUrl": "https://github.com/octo/app/actions/runs/1"},
+            {"__typename": "StatusContext", "context": "ci/legacy", "state": "PENDING",
+             "targetUrl": ""},
+            {"__typename": "FutureCheck", "name": "other"}
+        ],
+        "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-02T00:00:00Z",
+        "closedAt": null, "mergedAt": null
+    }"#;
+
+    #[test]
+    fn test_detail_decodes() {
+        let detail: PullRequestDetail = serde_json::from_str(DETAIL).unwrap();
+
+        assert_eq!(detail.state, PrState::Open);
+        assert!(detail.is_draft);
+        assert_eq!(detail.head_ref_name, "fix/crash");
+        assert_eq!(detail.labels[0].name, "bug");
+        assert_eq!(
+            detail.review_requests,
+            vec![
+                ReviewRequest::User {
+                    login: "hubot".to_string()
+                },
+                ReviewRequest::Team {
+                    name: "Core".to_string(),
+                    slug: "core".to_string()
+                },
+                ReviewRequest::Unknown,
+            ]
+        );
+        assert_eq!(detail.reviews[0].state, ReviewState::Approved);
+        assert_eq!(detail.reviews[1].state, ReviewState::Unknown);
+        assert_eq!(detail.reviews[1].submitted_at, None);
+        assert_eq!(detail.files[0].path, "src/main.rs");
+        assert_eq!(detail.commits[0].authors[0].name, "Octo Cat");
+        assert_eq!(detail.mergeable, Mergeable::Unknown);
+        assert_eq!(detail.merge_state_status, MergeStateStatus::Unknown);
+        assert!(matches!(
+            &detail.status_check_rollup[0],
+            StatusCheck::CheckRun { conclusion, workflow_name, .. }
+                if conclusion == "SUCCESS" && workflow_name == "CI"
+        ));
+        assert!(matches!(
+            &detail.status_check_rollup[1],
+            StatusCheck::StatusContext { context, state, .. }
+                if context == "ci/legacy" && state == "PENDING"
+        ));
+        assert_eq!(detail.status_check_rollup[2], StatusCheck::Unknown);
+        assert_eq!(detail.merged_at, None);
+    }
+
+    #[test]
+    fn test_compare_ref() {
+        assert_eq!(compare_ref("fix/crash"), "fix/crash");
+        assert_eq!(compare_ref("monalisa:fix/crash"), "monalisa:fix/crash");
+        assert_eq!(compare_ref("fix#1 & more"), "fix%231%20%26%20more");
+    }
+
+    #[test]
+    fn test_fetch_ignores_web() {
+        let args = vec![
+            "pr".to_string(),
+            "view".to_string(),
+            "7".to_string(),
+            "--json".to_string(),
+            PR_DETAIL_FIELDS.join(","),
+        ];
+        let executor =
+            GhExecutor::default().with_cassette(Cassette::from_interactions(vec![Interaction {
+                args,
+                env: BTreeMap::new(),
+                stdin: None,
+                stdout: DETAIL.to_string(),
+                stderr: String::new(),
+                exit_code: 0,
+            }]));
+
+        let detail = PrViewCommand::new(Arc::new(executor), 7.into())
+            .web()
+            .fetch()
+            .unwrap();
+        assert_eq!(detail.number, 7);
+    }
+}
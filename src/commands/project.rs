@@ -0,0 +1,1179 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::error::{GhError, Result};
+use crate::executor::GhExecutor;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Projects (v2) commands namespace
+///
+/// Commands target the authenticated user's projects (`--owner @me`) unless
+/// an owner is given with `.owner()`; gh itself only prompts for one.
+#[derive(Clone)]
+pub struct ProjectCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl ProjectCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List projects
+    pub fn list(&self) -> ProjectListCommand {
+        ProjectListCommand::new(self.executor.clone())
+    }
+
+    /// View a project
+    pub fn view(&self, number: u32) -> ProjectViewCommand {
+        ProjectViewCommand::new(self.executor.clone(), number)
+    }
+
+    /// Create a project
+    pub fn create(&self, title: &str) -> ProjectCreateCommand {
+        ProjectCreateCommand::new(self.executor.clone(), title)
+    }
+
+    /// Edit a project
+    pub fn edit(&self, number: u32) -> ProjectEditCommand {
+        ProjectEditCommand::new(self.executor.clone(), number)
+    }
+
+    /// Close a project
+    pub fn close(&self, number: u32) -> ProjectCloseCommand {
+        ProjectCloseCommand::new(self.executor.clone(), number)
+    }
+
+    /// Delete a project
+    pub fn delete(&self, number: u32) -> ProjectDeleteCommand {
+        ProjectDeleteCommand::new(self.executor.clone(), number)
+    }
+
+    /// Copy a project
+    pub fn copy(&self, number: u32) -> ProjectCopyCommand {
+        ProjectCopyCommand::new(self.executor.clone(), number)
+    }
+
+    /// List the items of a project
+    pub fn item_list(&self, number: u32) -> ProjectItemListCommand {
+        ProjectItemListCommand::new(self.executor.clone(), number)
+    }
+
+    /// Add an issue or pull request to a project by URL
+    pub fn item_add(&self, number: u32, url: &str) -> ProjectItemAddCommand {
+        ProjectItemAddCommand::new(self.executor.clone(), number, url)
+    }
+
+    /// Create a draft issue in a project
+    pub fn item_create(&self, number: u32, title: &str) -> ProjectItemCreateCommand {
+        ProjectItemCreateCommand::new(self.executor.clone(), number, title)
+    }
+
+    /// Edit a project item
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::{FieldValue, GhClient};
+    /// let client = GhClient::new();
+    /// client
+    ///     .project()
+    ///     .item_edit("PVTI_lADOANN5s84ACbL0zgBVd94")
+    ///     .project_id("PVT_kwDOANN5s84ACbL0")
+    ///     .field("PVTSSF_lADOANN5s84ACbL0zgBZrZY", FieldValue::SingleSelect("47fc9ee4".into()))
+    ///     .execute();
+    /// ```
+    pub fn item_edit(&self, item_id: &str) -> ProjectItemEditCommand {
+        ProjectItemEditCommand::new(self.executor.clone(), item_id)
+    }
+
+    /// Archive a project item
+    pub fn item_archive(&self, number: u32, item_id: &str) -> ProjectItemArchiveCommand {
+        ProjectItemArchiveCommand::new(self.executor.clone(), number, item_id)
+    }
+
+    /// Delete a project item
+    pub fn item_delete(&self, number: u32, item_id: &str) -> ProjectItemDeleteCommand {
+        ProjectItemDeleteCommand::new(self.executor.clone(), number, item_id)
+    }
+
+    /// List the fields of a project
+    pub fn field_list(&self, number: u32) -> ProjectFieldListCommand {
+        ProjectFieldListCommand::new(self.executor.clone(), number)
+    }
+
+    /// Create a field in a project
+    pub fn field_create(
+        &self,
+        number: u32,
+        name: &str,
+        data_type: FieldDataType,
+    ) -> ProjectFieldCreateCommand {
+        ProjectFieldCreateCommand::new(self.executor.clone(), number, name, data_type)
+    }
+
+    /// Link a project to a repository or team
+    pub fn link(&self, number: u32) -> ProjectLinkCommand {
+        ProjectLinkCommand::new(self.executor.clone(), "link", number)
+    }
+
+    /// Unlink a project from a repository or team
+    pub fn unlink(&self, number: u32) -> ProjectLinkCommand {
+        ProjectLinkCommand::new(self.executor.clone(), "unlink", number)
+    }
+}
+
+fn project_cmd(subcommand: &str, number: Option<u32>) -> BaseCommand {
+    let cmd = BaseCommand::with_subcommands(&["project", subcommand]);
+    match number {
+        Some(number) => cmd.arg(&number.to_string()),
+        None => cmd,
+    }
+}
+
+/// A project command for the current user's projects, see `with_owner`
+fn owned_cmd(subcommand: &str, number: Option<u32>) -> BaseCommand {
+    project_cmd(subcommand, number).option("--owner", "@me")
+}
+
+/// Replace the value of an option set when the command was created
+fn with_owner(mut cmd: BaseCommand, flag: &str, owner: &str) -> BaseCommand {
+    match cmd.args.iter().position(|arg| arg == flag) {
+        Some(i) => cmd.args[i + 1] = owner.to_string(),
+        None => cmd = cmd.option(flag, owner),
+    }
+    cmd
+}
+
+fn json(cmd: &BaseCommand) -> Vec<String> {
+    cmd.clone().option("--format", "json").build_args()
+}
+
+/// Item and field counts of a project
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotalCount {
+    pub total_count: u32,
+}
+
+/// Owner of a project
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ProjectOwner {
+    /// `User` or `Organization`
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub login: String,
+}
+
+/// A project (v2)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    pub id: String,
+    pub number: u32,
+    pub title: String,
+    #[serde(default)]
+    pub short_description: String,
+    #[serde(default)]
+    pub readme: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub public: bool,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(default)]
+    pub template: bool,
+    #[serde(default)]
+    pub owner: Option<ProjectOwner>,
+    #[serde(default)]
+    pub items: TotalCount,
+    #[serde(default)]
+    pub fields: TotalCount,
+}
+
+#[derive(Deserialize)]
+struct ProjectPage {
+    projects: Vec<Project>,
+}
+
+/// Kind of content behind a project item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum ProjectItemType {
+    Issue,
+    PullRequest,
+    DraftIssue,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Issue, pull request or draft issue behind a project item
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ProjectItemContent {
+    #[serde(rename = "type")]
+    pub kind: ProjectItemType,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub body: String,
+    #[serde(default)]
+    pub number: Option<u32>,
+    /// `owner/name` of the repository, absent for draft issues
+    #[serde(default)]
+    pub repository: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// A project item
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ProjectItem {
+    pub id: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default, rename = "type")]
+    pub kind: Option<ProjectItemType>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub content: Option<ProjectItemContent>,
+    /// Custom field values keyed by the lowercased field name (e.g. `status`)
+    #[serde(flatten)]
+    pub fields: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct ItemPage {
+    items: Vec<ProjectItem>,
+}
+
+/// Kind of a project field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum ProjectFieldKind {
+    #[serde(rename = "ProjectV2Field")]
+    Field,
+    #[serde(rename = "ProjectV2SingleSelectField")]
+    SingleSelect,
+    #[serde(rename = "ProjectV2IterationField")]
+    Iteration,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Option of a single-select field
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ProjectFieldOption {
+    pub id: String,
+    pub name: String,
+}
+
+/// A project field
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ProjectField {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: ProjectFieldKind,
+    /// Options of a single-select field
+    #[serde(default)]
+    pub options: Vec<ProjectFieldOption>,
+}
+
+#[derive(Deserialize)]
+struct FieldPage {
+    fields: Vec<ProjectField>,
+}
+
+/// Data type of a field created with `field_create`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldDataType {
+    Text,
+    Number,
+    Date,
+    SingleSelect,
+}
+
+impl FieldDataType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FieldDataType::Text => "TEXT",
+            FieldDataType::Number => "NUMBER",
+            FieldDataType::Date => "DATE",
+            FieldDataType::SingleSelect => "SINGLE_SELECT",
+        }
+    }
+}
+
+/// Value to set on a project item field
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Text(String),
+    Number(f64),
+    /// Date in `YYYY-MM-DD` format
+    Date(String),
+    /// ID of a single-select option
+    SingleSelect(String),
+    /// ID of an iteration
+    Iteration(String),
+    /// Remove the current value
+    Clear,
+}
+
+impl FieldValue {
+    fn apply(&self, cmd: BaseCommand) -> BaseCommand {
+        match self {
+            FieldValue::Text(text) => cmd.option("--text", text),
+            FieldValue::Number(number) => cmd.option("--number", &number.to_string()),
+            FieldValue::Date(date) => cmd.option("--date", date),
+            FieldValue::SingleSelect(id) => cmd.option("--single-select-option-id", id),
+            FieldValue::Iteration(id) => cmd.option("--iteration-id", id),
+            FieldValue::Clear => cmd.flag("--clear"),
+        }
+    }
+}
+
+/// Command for listing projects
+pub struct ProjectListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("list", None),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Include closed projects
+    pub fn closed(mut self) -> Self {
+        self.cmd = self.cmd.flag("--closed");
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the projects
+    pub fn fetch(&self) -> Result<Vec<Project>> {
+        let page: ProjectPage = self.executor.execute_json(&json(&self.cmd))?;
+        Ok(page.projects)
+    }
+}
+
+impl GhCommand for ProjectListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for viewing a project
+pub struct ProjectViewCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectViewCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("view", Some(number)),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Open in web browser
+    pub fn web(mut self) -> Self {
+        self.cmd = self.cmd.flag("--web");
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the project
+    pub fn fetch(&self) -> Result<Project> {
+        self.executor
+            .execute_json(&json(&self.cmd.without_flag("--web")))
+    }
+
+    /// Get the web URL without opening a browser
+    pub fn url(&self) -> Result<String> {
+        let mut args = json(&self.cmd.without_flag("--web"));
+        args.extend(["--jq".to_string(), ".url".to_string()]);
+        Ok(self.executor.execute(&args)?.trim().to_string())
+    }
+}
+
+impl GhCommand for ProjectViewCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for creating a project
+pub struct ProjectCreateCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectCreateCommand {
+    fn new(executor: Arc<GhExecutor>, title: &str) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("create", None).option("--title", title),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the created project
+    pub fn fetch(&self) -> Result<Project> {
+        self.executor.execute_json(&json(&self.cmd))
+    }
+}
+
+impl GhCommand for ProjectCreateCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for editing a project
+pub struct ProjectEditCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectEditCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("edit", Some(number)),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Set the title
+    pub fn title(mut self, title: &str) -> Self {
+        self.cmd = self.cmd.option("--title", title);
+        self
+    }
+
+    /// Set the short description
+    pub fn description(mut self, desc: &str) -> Self {
+        self.cmd = self.cmd.option("--description", desc);
+        self
+    }
+
+    /// Set the readme
+    pub fn readme(mut self, readme: &str) -> Self {
+        self.cmd = self.cmd.option("--readme", readme);
+        self
+    }
+
+    /// Make the project public or private
+    pub fn public(mut self, public: bool) -> Self {
+        let visibility = if public { "PUBLIC" } else { "PRIVATE" };
+        self.cmd = self.cmd.option("--visibility", visibility);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the edited project
+    pub fn fetch(&self) -> Result<Project> {
+        self.executor.execute_json(&json(&self.cmd))
+    }
+}
+
+impl GhCommand for ProjectEditCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for closing or reopening a project
+pub struct ProjectCloseCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectCloseCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("close", Some(number)),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Reopen a closed project instead
+    pub fn undo(mut self) -> Self {
+        self.cmd = self.cmd.flag("--undo");
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the project
+    pub fn fetch(&self) -> Result<Project> {
+        self.executor.execute_json(&json(&self.cmd))
+    }
+}
+
+impl GhCommand for ProjectCloseCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for deleting a project
+pub struct ProjectDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectDeleteCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("delete", Some(number)),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the deleted project
+    pub fn fetch(&self) -> Result<Project> {
+        self.executor.execute_json(&json(&self.cmd))
+    }
+}
+
+impl GhCommand for ProjectDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for copying a project
+pub struct ProjectCopyCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectCopyCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+        Self {
+            executor,
+            cmd: project_cmd("copy", Some(number))
+                .option("--source-owner", "@me")
+                .option("--target-owner", "@me"),
+        }
+    }
+
+    /// Login of the owner of the project to copy
+    pub fn source_owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--source-owner", owner);
+        self
+    }
+
+    /// Login of the owner of the new project
+    pub fn target_owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--target-owner", owner);
+        self
+    }
+
+    /// Title of the new project
+    pub fn title(mut self, title: &str) -> Self {
+        self.cmd = self.cmd.option("--title", title);
+        self
+    }
+
+    /// Also copy draft issues
+    pub fn drafts(mut self) -> Self {
+        self.cmd = self.cmd.flag("--drafts");
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the new project
+    pub fn fetch(&self) -> Result<Project> {
+        self.executor.execute_json(&json(&self.cmd))
+    }
+}
+
+impl GhCommand for ProjectCopyCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for listing project items
+pub struct ProjectItemListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectItemListCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("item-list", Some(number)),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the items
+    pub fn fetch(&self) -> Result<Vec<ProjectItem>> {
+        let page: ItemPage = self.executor.execute_json(&json(&self.cmd))?;
+        Ok(page.items)
+    }
+}
+
+impl GhCommand for ProjectItemListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for adding an issue or pull request to a project
+pub struct ProjectItemAddCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectItemAddCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32, url: &str) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("item-add", Some(number)).option("--url", url),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the added item
+    pub fn fetch(&self) -> Result<ProjectItem> {
+        self.executor.execute_json(&json(&self.cmd))
+    }
+}
+
+impl GhCommand for ProjectItemAddCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for creating a draft issue in a project
+pub struct ProjectItemCreateCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectItemCreateCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32, title: &str) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("item-create", Some(number)).option("--title", title),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Set the body
+    pub fn body(mut self, body: &str) -> Self {
+        self.cmd = self.cmd.option("--body", body);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the created item
+    pub fn fetch(&self) -> Result<ProjectItem> {
+        self.executor.execute_json(&json(&self.cmd))
+    }
+}
+
+impl GhCommand for ProjectItemCreateCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for editing a project item
+///
+/// Field values need both `.project_id()` and `.field()`; draft issues can
+/// also be edited with `.title()` and `.body()`.
+pub struct ProjectItemEditCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    project_id: Option<String>,
+    field: Option<(String, FieldValue)>,
+}
+
+impl ProjectItemEditCommand {
+    fn new(executor: Arc<GhExecutor>, item_id: &str) -> Self {
+        Self {
+            executor,
+            cmd: project_cmd("item-edit", None).option("--id", item_id),
+            project_id: None,
+            field: None,
+        }
+    }
+
+    /// Node ID of the project (see `Project::id`)
+    pub fn project_id(mut self, project_id: &str) -> Self {
+        self.project_id = Some(project_id.to_string());
+        self
+    }
+
+    /// Set the value of a field (see `ProjectField::id`)
+    pub fn field(mut self, field_id: &str, value: FieldValue) -> Self {
+        self.field = Some((field_id.to_string(), value));
+        self
+    }
+
+    /// Set the title of a draft issue
+    pub fn title(mut self, title: &str) -> Self {
+        self.cmd = self.cmd.option("--title", title);
+        self
+    }
+
+    /// Set the body of a draft issue
+    pub fn body(mut self, body: &str) -> Self {
+        self.cmd = self.cmd.option("--body", body);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.field.is_some() && self.project_id.is_none() {
+            return Err(GhError::InvalidCommand(
+                "editing a project item field requires project_id".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        self.validate()?;
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the edited item
+    pub fn fetch(&self) -> Result<ProjectItem> {
+        self.validate()?;
+        let mut args = self.build_args();
+        args.extend(["--format".to_string(), "json".to_string()]);
+        self.executor.execute_json(&args)
+    }
+}
+
+impl GhCommand for ProjectItemEditCommand {
+    fn build_args(&self) -> Vec<String> {
+        let mut cmd = self.cmd.clone();
+        if let Some(project_id) = &self.project_id {
+            cmd = cmd.option("--project-id", project_id);
+        }
+        if let Some((field_id, value)) = &self.field {
+            cmd = value.apply(cmd.option("--field-id", field_id));
+        }
+        cmd.build_args()
+    }
+}
+
+/// Command for archiving or unarchiving a project item
+pub struct ProjectItemArchiveCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectItemArchiveCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32, item_id: &str) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("item-archive", Some(number)).option("--id", item_id),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Unarchive the item instead
+    pub fn undo(mut self) -> Self {
+        self.cmd = self.cmd.flag("--undo");
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the item
+    pub fn fetch(&self) -> Result<ProjectItem> {
+        self.executor.execute_json(&json(&self.cmd))
+    }
+}
+
+impl GhCommand for ProjectItemArchiveCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for deleting a project item
+pub struct ProjectItemDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectItemDeleteCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32, item_id: &str) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("item-delete", Some(number)).option("--id", item_id),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the deleted item
+    pub fn fetch(&self) -> Result<ProjectItem> {
+        self.executor.execute_json(&json(&self.cmd))
+    }
+}
+
+impl GhCommand for ProjectItemDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for listing project fields
+pub struct ProjectFieldListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectFieldListCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("field-list", Some(number)),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the fields
+    pub fn fetch(&self) -> Result<Vec<ProjectField>> {
+        let page: FieldPage = self.executor.execute_json(&json(&self.cmd))?;
+        Ok(page.fields)
+    }
+}
+
+impl GhCommand for ProjectFieldListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for creating a project field
+pub struct ProjectFieldCreateCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectFieldCreateCommand {
+    fn new(executor: Arc<GhExecutor>, number: u32, name: &str, data_type: FieldDataType) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd("field-create", Some(number))
+                .option("--name", name)
+                .option("--data-type", data_type.as_str()),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Options of a single-select field
+    pub fn single_select_options(mut self, options: &[&str]) -> Self {
+        self.cmd = self
+            .cmd
+            .option("--single-select-options", &options.join(","));
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the created field
+    pub fn fetch(&self) -> Result<ProjectField> {
+        self.executor.execute_json(&json(&self.cmd))
+    }
+}
+
+impl GhCommand for ProjectFieldCreateCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for linking or unlinking a project to a repository or team
+pub struct ProjectLinkCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl ProjectLinkCommand {
+    fn new(executor: Arc<GhExecutor>, action: &str, number: u32) -> Self {
+        Self {
+            executor,
+            cmd: owned_cmd(action, Some(number)),
+        }
+    }
+
+    /// Login of the owner (defaults to `@me`, the current user)
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = with_owner(self.cmd, "--owner", owner);
+        self
+    }
+
+    /// Repository to link
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Team to link
+    pub fn team(mut self, team: &str) -> Self {
+        self.cmd = self.cmd.option("--team", team);
+        self
+    }
+
+    /// Execute the command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for ProjectLinkCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_value_args() {
+        let cmd = FieldValue::Number(3.5).apply(BaseCommand::new("x"));
+        assert_eq!(cmd.build_args(), vec!["x", "--number", "3.5"]);
+        let cmd = FieldValue::Clear.apply(BaseCommand::new("x"));
+        assert_eq!(cmd.build_args(), vec!["x", "--clear"]);
+    }
+
+    #[test]
+    fn test_item_edit_requires_project_id() {
+        let executor = Arc::new(GhExecutor::default());
+        let edit = ProjectItemEditCommand::new(executor, "PVTI_1")
+            .field("PVTF_1", FieldValue::Text("hi".into()));
+        assert!(matches!(edit.execute(), Err(GhError::InvalidCommand(_))));
+
+        let edit = edit.project_id("PVT_1");
+        assert_eq!(
+            edit.build_args(),
+            vec![
+                "project",
+                "item-edit",
+                "--id",
+                "PVTI_1",
+                "--project-id",
+                "PVT_1",
+                "--field-id",
+                "PVTF_1",
+                "--text",
+                "hi"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_owner_defaults_to_current_user() {
+        let executor = Arc::new(GhExecutor::default());
+        assert_eq!(
+            ProjectViewCommand::new(executor.clone(), 1).build_args(),
+            vec!["project", "view", "1", "--owner", "@me"]
+        );
+        assert_eq!(
+            ProjectItemAddCommand::new(executor.clone(), 1, "https://github.com/o/r/issues/1")
+                .owner("octo")
+                .build_args(),
+            vec![
+                "project",
+                "item-add",
+                "1",
+                "--owner",
+                "octo",
+                "--url",
+                "https://github.com/o/r/issues/1"
+            ]
+        );
+        assert_eq!(
+            ProjectCopyCommand::new(executor, 1)
+                .target_owner("octo")
+                .build_args(),
+            vec![
+                "project",
+                "copy",
+                "1",
+                "--source-owner",
+                "@me",
+                "--target-owner",
+                "octo"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_item_list_decoding() {
+        let json = r#"{"items":[{"id":"PVTI_1","title":"Fix it","status":"Done",
+            "content":{"type":"Issue","title":"Fix it","body":"","number":7,
+            "repository":"octo/app","url":"https://github.com/octo/app/issues/7"}}],
+            "totalCount":1}"#;
+        let page: ItemPage = serde_json::from_str(json).unwrap();
+        let item = &page.items[0];
+        assert_eq!(item.content.as_ref().unwrap().kind, ProjectItemType::Issue);
+        assert_eq!(item.fields["status"], "Done");
+        assert!(!item.fields.contains_key("content"));
+    }
+}
@@ -1,6 +1,11 @@
 use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::autolink::AutolinkCommands;
+use crate::commands::deploy_key::DeployKeyCommands;
 use crate::error::Result;
 use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 /// Repository commands namespace
@@ -44,12 +49,104 @@ impl RepoCommands {
     pub fn view(&self, repo: Option<&str>) -> RepoViewCommand {
         RepoViewCommand::new(self.executor.clone(), repo)
     }
+
+    /// Edit repository settings
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// client
+    ///     .repo()
+    ///     .edit(Some("owner/repo"))
+    ///     .enable_wiki(false)
+    ///     .delete_branch_on_merge(true)
+    ///     .add_topic("rust")
+    ///     .execute();
+    /// ```
+    pub fn edit(&self, repo: Option<&str>) -> RepoEditCommand {
+        RepoEditCommand::new(self.executor.clone(), repo)
+    }
+
+    /// Delete a repository
+    pub fn delete(&self, repo: Option<&str>) -> RepoDeleteCommand {
+        RepoDeleteCommand::new(self.executor.clone(), repo)
+    }
+
+    /// Archive a repository
+    pub fn archive(&self, repo: Option<&str>) -> RepoArchiveCommand {
+        RepoArchiveCommand::new(self.executor.clone(), "archive", repo)
+    }
+
+    /// Unarchive a repository
+    pub fn unarchive(&self, repo: Option<&str>) -> RepoArchiveCommand {
+        RepoArchiveCommand::new(self.executor.clone(), "unarchive", repo)
+    }
+
+    /// Rename a repository
+    pub fn rename(&self, new_name: &str) -> RepoRenameCommand {
+        RepoRenameCommand::new(self.executor.clone(), new_name)
+    }
+
+    /// Sync a repository branch from its source
+    pub fn sync(&self, destination: Option<&str>) -> RepoSyncCommand {
+        RepoSyncCommand::new(self.executor.clone(), destination)
+    }
+
+    /// Set the default repository for the current directory
+    pub fn set_default(&self, repo: Option<&str>) -> RepoSetDefaultCommand {
+        RepoSetDefaultCommand::new(self.executor.clone(), repo)
+    }
+
+    /// Access deploy key commands
+    pub fn deploy_key(&self) -> DeployKeyCommands {
+        DeployKeyCommands::new(self.executor.clone())
+    }
+
+    /// Access autolink reference commands
+    pub fn autolink(&self) -> AutolinkCommands {
+        AutolinkCommands::new(self.executor.clone())
+    }
+}
+
+/// Repository visibility
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Visibility {
+    #[serde(alias = "public")]
+    Public,
+    #[serde(alias = "private")]
+    Private,
+    #[serde(alias = "internal")]
+    Internal,
+}
+
+impl Visibility {
+    /// Value as accepted by gh flags
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Public => "public",
+            Visibility::Private => "private",
+            Visibility::Internal => "internal",
+        }
+    }
+}
+
+/// Start a command with an optional repository argument
+fn with_repo(subcommands: &[&str], repo: Option<&str>) -> BaseCommand {
+    let cmd = BaseCommand::with_subcommands(subcommands);
+    match repo {
+        Some(repo) => cmd.arg(repo),
+        None => cmd,
+    }
 }
 
 /// Command for cloning a repository
 pub struct RepoCloneCommand {
     executor: Arc<GhExecutor>,
     cmd: BaseCommand,
+    repo: String,
+    directory: Option<String>,
+    git_flags: Vec<String>,
 }
 
 impl RepoCloneCommand {
@@ -57,25 +154,70 @@ impl RepoCloneCommand {
         Self {
             executor,
             cmd: BaseCommand::with_subcommands(&["repo", "clone"]).arg(repo),
+            repo: repo.to_string(),
+            directory: None,
+            git_flags: Vec::new(),
         }
     }
 
-    /// Execute the clone command
-    pub fn execute(&self) -> Result<String> {
-        GhCommand::execute(self, self.executor.as_ref())
+    /// Clone into the given directory instead of one named after the repository
+    pub fn directory(mut self, directory: &str) -> Self {
+        self.directory = Some(directory.to_string());
+        self
+    }
+
+    /// Name of the remote for the parent repository when cloning a fork
+    pub fn upstream_remote_name(mut self, name: &str) -> Self {
+        self.cmd = self.cmd.option("--upstream-remote-name", name);
+        self
+    }
+
+    /// Pass extra flags to `git clone` (e.g. `&["--depth", "1"]`)
+    pub fn git_flags(mut self, flags: &[&str]) -> Self {
+        self.git_flags.extend(flags.iter().map(|f| f.to_string()));
+        self
+    }
+
+    /// Local path the repository is cloned into
+    pub fn local_path(&self) -> PathBuf {
+        PathBuf::from(
+            self.directory
+                .clone()
+                .unwrap_or_else(|| repo_name(&self.repo).to_string()),
+        )
+    }
+
+    /// Execute the clone command and return the local path of the clone
+    pub fn execute(&self) -> Result<PathBuf> {
+        GhCommand::execute(self, self.executor.as_ref())?;
+        Ok(self.local_path())
     }
 }
 
 impl GhCommand for RepoCloneCommand {
     fn build_args(&self) -> Vec<String> {
-        self.cmd.build_args()
+        let mut args = self.cmd.build_args();
+        args.extend(self.directory.clone());
+        if !self.git_flags.is_empty() {
+            args.push("--".to_string());
+            args.extend(self.git_flags.iter().cloned());
+        }
+        args
     }
 }
 
+/// Repository name from "owner/repo", a URL or an SSH remote, without ".git"
+fn repo_name(repo: &str) -> &str {
+    let repo = repo.trim_end_matches('/');
+    let name = repo.rsplit(['/', ':']).next().unwrap_or(repo);
+    name.strip_suffix(".git").unwrap_or(name)
+}
+
 /// Command for creating a repository
 pub struct RepoCreateCommand {
     executor: Arc<GhExecutor>,
     cmd: BaseCommand,
+    visibility: Option<Visibility>,
 }
 
 impl RepoCreateCommand {
@@ -83,18 +225,13 @@ impl RepoCreateCommand {
         Self {
             executor,
             cmd: BaseCommand::with_subcommands(&["repo", "create"]).arg(name),
+            visibility: None,
         }
     }
 
-    /// Set repository visibility to public
-    pub fn public(mut self) -> Self {
-        self.cmd = self.cmd.flag("--public");
-        self
-    }
-
-    /// Set repository visibility to private
-    pub fn private(mut self) -> Self {
-        self.cmd = self.cmd.flag("--private");
+    /// Set repository visibility (required when not interactive)
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = Some(visibility);
         self
     }
 
@@ -116,6 +253,85 @@ impl RepoCreateCommand {
         self
     }
 
+    /// Add a .gitignore from a template (e.g. "Rust")
+    pub fn gitignore(mut self, template: &str) -> Self {
+        self.cmd = self.cmd.option("--gitignore", template);
+        self
+    }
+
+    /// Add a license (e.g. "mit", "apache-2.0")
+    pub fn license(mut self, license: &str) -> Self {
+        self.cmd = self.cmd.option("--license", license);
+        self
+    }
+
+    /// Create from a template repository
+    pub fn template(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--template", repo);
+        self
+    }
+
+    /// Copy all branches of the template, not just the default branch
+    pub fn include_all_branches(mut self) -> Self {
+        self.cmd = self.cmd.flag("--include-all-branches");
+        self
+    }
+
+    /// Grant access to an organization team
+    pub fn team(mut self, team: &str) -> Self {
+        self.cmd = self.cmd.option("--team", team);
+        self
+    }
+
+    /// Disable issues
+    pub fn disable_issues(mut self) -> Self {
+        self.cmd = self.cmd.flag("--disable-issues");
+        self
+    }
+
+    /// Disable the wiki
+    pub fn disable_wiki(mut self) -> Self {
+        self.cmd = self.cmd.flag("--disable-wiki");
+        self
+    }
+
+    /// Clone the new repository into the current directory
+    pub fn clone(mut self) -> Self {
+        self.cmd = self.cmd.flag("--clone");
+        self
+    }
+
+    /// Publish an existing local directory as the repository
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::{GhClient, Visibility};
+    /// let client = GhClient::new();
+    /// client
+    ///     .repo()
+    ///     .create("my-tool")
+    ///     .visibility(Visibility::Private)
+    ///     .source(".")
+    ///     .remote("origin")
+    ///     .push()
+    ///     .execute();
+    /// ```
+    pub fn source(mut self, path: &str) -> Self {
+        self.cmd = self.cmd.option("--source", path);
+        self
+    }
+
+    /// Push local commits to the new repository (with `source`)
+    pub fn push(mut self) -> Self {
+        self.cmd = self.cmd.flag("--push");
+        self
+    }
+
+    /// Name of the remote to add for the new repository (with `source`)
+    pub fn remote(mut self, name: &str) -> Self {
+        self.cmd = self.cmd.option("--remote", name);
+        self
+    }
+
     /// Execute the create command
     pub fn execute(&self) -> Result<String> {
         GhCommand::execute(self, self.executor.as_ref())
@@ -124,14 +340,57 @@ impl RepoCreateCommand {
 
 impl GhCommand for RepoCreateCommand {
     fn build_args(&self) -> Vec<String> {
-        self.cmd.build_args()
+        let mut args = self.cmd.build_args();
+        if let Some(visibility) = self.visibility {
+            args.push(format!("--{}", visibility.as_str()));
+        }
+        args
+    }
+}
+
+/// A fork created by `RepoForkCommand`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForkedRepo {
+    pub owner: String,
+    pub name: String,
+    /// Local path of the clone, when the fork was cloned
+    pub local_path: Option<PathBuf>,
+}
+
+impl ForkedRepo {
+    /// Full name in the form "owner/name"
+    pub fn full_name(&self) -> String {
+        format!("{}/{}", self.owner, self.name)
     }
 }
 
+/// Repository as returned by the REST API
+#[derive(Deserialize)]
+struct ApiRepo {
+    name: String,
+    owner: ApiOwner,
+}
+
+#[derive(Deserialize)]
+struct ApiOwner {
+    login: String,
+}
+
 /// Command for forking a repository
+///
+/// The fork is created with `gh api -X POST repos/{repo}/forks`, whose response
+/// names the actual fork: GitHub returns an existing fork instead of creating
+/// another, and may rename a new one on conflict. With `clone` or `remote`,
+/// `gh repo fork` then sets up that fork locally.
 pub struct RepoForkCommand {
     executor: Arc<GhExecutor>,
     cmd: BaseCommand,
+    repo: String,
+    org: Option<String>,
+    fork_name: Option<String>,
+    default_branch_only: bool,
+    clone: bool,
+    remote: bool,
 }
 
 impl RepoForkCommand {
@@ -139,24 +398,104 @@ impl RepoForkCommand {
         Self {
             executor,
             cmd: BaseCommand::with_subcommands(&["repo", "fork"]).arg(repo),
+            repo: repo.to_string(),
+            org: None,
+            fork_name: None,
+            default_branch_only: false,
+            clone: false,
+            remote: false,
         }
     }
 
     /// Clone the fork after creating it
     pub fn clone(mut self) -> Self {
         self.cmd = self.cmd.flag("--clone");
+        self.clone = true;
         self
     }
 
-    /// Execute the fork command
-    pub fn execute(&self) -> Result<String> {
-        GhCommand::execute(self, self.executor.as_ref())
+    /// Create the fork in an organization
+    pub fn org(mut self, org: &str) -> Self {
+        self.cmd = self.cmd.option("--org", org);
+        self.org = Some(org.to_string());
+        self
+    }
+
+    /// Name the fork differently from the source repository
+    ///
+    /// An already existing fork is returned under its current name.
+    pub fn fork_name(mut self, name: &str) -> Self {
+        self.fork_name = Some(name.to_string());
+        self
+    }
+
+    /// Only include the default branch in the fork
+    pub fn default_branch_only(mut self) -> Self {
+        self.default_branch_only = true;
+        self
+    }
+
+    /// Add a git remote for the fork in the current repository
+    pub fn remote(mut self) -> Self {
+        self.cmd = self.cmd.flag("--remote");
+        self.remote = true;
+        self
+    }
+
+    /// Name of the remote for the fork (with `remote` or `clone`)
+    pub fn remote_name(mut self, name: &str) -> Self {
+        self.cmd = self.cmd.option("--remote-name", name);
+        self
+    }
+
+    /// Execute the fork command and return the fork
+    pub fn execute(&self) -> Result<ForkedRepo> {
+        let fork: ApiRepo = self.executor.execute_json(&self.build_args())?;
+
+        // The fork exists now, so `gh repo fork` only clones or adds the remote
+        if self.clone || self.remote {
+            self.executor.execute(&self.cmd.build_args())?;
+        }
+        let local_path = if self.clone {
+            Some(PathBuf::from(&fork.name))
+        } else {
+            None
+        };
+
+        Ok(ForkedRepo {
+            owner: fork.owner.login,
+            name: fork.name,
+            local_path,
+        })
     }
 }
 
 impl GhCommand for RepoForkCommand {
     fn build_args(&self) -> Vec<String> {
-        self.cmd.build_args()
+        let mut args: Vec<String> = ["api", "-X", "POST"].map(String::from).to_vec();
+        args.push(format!("repos/{}/forks", repo_full_name(&self.repo)));
+        for (key, value) in [("organization", &self.org), ("name", &self.fork_name)] {
+            if let Some(value) = value {
+                args.push("-f".to_string());
+                args.push(format!("{}={}", key, value));
+            }
+        }
+        if self.default_branch_only {
+            args.push("-F".to_string());
+            args.push("default_branch_only=true".to_string());
+        }
+        args
+    }
+}
+
+/// "owner/name" from "owner/name", a URL or an SSH remote, without ".git"
+fn repo_full_name(repo: &str) -> String {
+    let repo = repo.trim_end_matches('/');
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+    let mut parts = repo.rsplit(['/', ':']);
+    match (parts.next(), parts.next()) {
+        (Some(name), Some(owner)) => format!("{}/{}", owner, name),
+        _ => repo.to_string(),
     }
 }
 
@@ -186,6 +525,46 @@ impl RepoListCommand {
         self
     }
 
+    /// Filter by visibility
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.cmd = self.cmd.option("--visibility", visibility.as_str());
+        self
+    }
+
+    /// Show only non-fork repositories
+    pub fn source(mut self) -> Self {
+        self.cmd = self.cmd.flag("--source");
+        self
+    }
+
+    /// Show only forks
+    pub fn fork(mut self) -> Self {
+        self.cmd = self.cmd.flag("--fork");
+        self
+    }
+
+    /// Show only archived (`true`) or only unarchived (`false`) repositories
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.cmd = self.cmd.flag(if archived {
+            "--archived"
+        } else {
+            "--no-archived"
+        });
+        self
+    }
+
+    /// Filter by primary language
+    pub fn language(mut self, language: &str) -> Self {
+        self.cmd = self.cmd.option("--language", language);
+        self
+    }
+
+    /// Filter by topic
+    pub fn topic(mut self, topic: &str) -> Self {
+        self.cmd = self.cmd.option("--topic", topic);
+        self
+    }
+
     /// Execute and get raw output
     pub fn execute(&self) -> Result<String> {
         GhCommand::execute(self, self.executor.as_ref())
@@ -198,6 +577,153 @@ impl GhCommand for RepoListCommand {
     }
 }
 
+/// Fields requested by `RepoViewCommand::fetch`
+const REPO_DETAIL_FIELDS: &[&str] = &[
+    "name",
+    "nameWithOwner",
+    "owner",
+    "description",
+    "url",
+    "homepageUrl",
+    "defaultBranchRef",
+    "primaryLanguage",
+    "languages",
+    "licenseInfo",
+    "repositoryTopics",
+    "stargazerCount",
+    "forkCount",
+    "watchers",
+    "visibility",
+    "isArchived",
+    "isFork",
+    "isTemplate",
+    "hasIssuesEnabled",
+    "hasWikiEnabled",
+    "createdAt",
+    "updatedAt",
+    "pushedAt",
+];
+
+/// License of a repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct License {
+    /// SPDX-like key, e.g. "mit"
+    pub key: String,
+    pub name: String,
+}
+
+/// A language used in a repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "LanguageEdge")]
+pub struct Language {
+    pub name: String,
+    /// Bytes of code in the language
+    pub size: u64,
+}
+
+#[derive(Deserialize)]
+struct LanguageEdge {
+    size: u64,
+    node: Named,
+}
+
+impl From<LanguageEdge> for Language {
+    fn from(edge: LanguageEdge) -> Self {
+        Self {
+            name: edge.node.name,
+            size: edge.size,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Named {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Count {
+    total_count: u64,
+}
+
+fn name_of<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<String, D::Error> {
+    Ok(Option::<Named>::deserialize(deserializer)?
+        .map(|named| named.name)
+        .unwrap_or_default())
+}
+
+fn optional_name_of<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error> {
+    Ok(Option::<Named>::deserialize(deserializer)?.map(|named| named.name))
+}
+
+fn names_of<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Vec<String>, D::Error> {
+    Ok(Option::<Vec<Named>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|named| named.name)
+        .collect())
+}
+
+fn login_of<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<String, D::Error> {
+    #[derive(Deserialize)]
+    struct Login {
+        login: String,
+    }
+    Ok(Login::deserialize(deserializer)?.login)
+}
+
+fn total_count_of<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<u64, D::Error> {
+    Ok(Option::<Count>::deserialize(deserializer)?.map_or(0, |count| count.total_count))
+}
+
+/// Repository details returned by `RepoViewCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryDetail {
+    pub name: String,
+    pub name_with_owner: String,
+    #[serde(deserialize_with = "login_of")]
+    pub owner: String,
+    #[serde(default)]
+    pub description: String,
+    pub url: String,
+    #[serde(default)]
+    pub homepage_url: String,
+    #[serde(rename = "defaultBranchRef", deserialize_with = "name_of", default)]
+    pub default_branch: String,
+    #[serde(deserialize_with = "optional_name_of", default)]
+    pub primary_language: Option<String>,
+    #[serde(default)]
+    pub languages: Vec<Language>,
+    #[serde(rename = "licenseInfo", default)]
+    pub license: Option<License>,
+    #[serde(rename = "repositoryTopics", deserialize_with = "names_of", default)]
+    pub topics: Vec<String>,
+    #[serde(rename = "stargazerCount")]
+    pub stargazers: u64,
+    #[serde(rename = "forkCount")]
+    pub forks: u64,
+    #[serde(deserialize_with = "total_count_of", default)]
+    pub watchers: u64,
+    pub visibility: Visibility,
+    pub is_archived: bool,
+    pub is_fork: bool,
+    pub is_template: bool,
+    pub has_issues_enabled: bool,
+    pub has_wiki_enabled: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    #[serde(default)]
+    pub pushed_at: Option<Timestamp>,
+}
+
 /// Command for viewing repository details
 pub struct RepoViewCommand {
     executor: Arc<GhExecutor>,
@@ -206,11 +732,10 @@ pub struct RepoViewCommand {
 
 impl RepoViewCommand {
     fn new(executor: Arc<GhExecutor>, repo: Option<&str>) -> Self {
-        let mut cmd = BaseCommand::with_subcommands(&["repo", "view"]);
-        if let Some(repo) = repo {
-            cmd = cmd.arg(repo);
+        Self {
+            executor,
+            cmd: with_repo(&["repo", "view"], repo),
         }
-        Self { executor, cmd }
     }
 
     /// Open in web browser
@@ -219,10 +744,37 @@ impl RepoViewCommand {
         self
     }
 
+    /// View a specific branch (affects the README shown)
+    pub fn branch(mut self, branch: &str) -> Self {
+        self.cmd = self.cmd.option("--branch", branch);
+        self
+    }
+
     /// Execute and get raw output
     pub fn execute(&self) -> Result<String> {
         GhCommand::execute(self, self.executor.as_ref())
     }
+
+    /// Execute and parse the repository details
+    pub fn fetch(&self) -> Result<RepositoryDetail> {
+        let cmd = self
+            .cmd
+            .without_flag("--web")
+            .option("--json", &REPO_DETAIL_FIELDS.join(","));
+        self.executor.execute_json(&cmd.build_args())
+    }
+
+    /// Get the web URL without opening a browser
+    pub fn url(&self) -> Result<String> {
+        let cmd = self
+            .cmd
+            .without_flag("--web")
+            .option("--json", "url")
+            .option("--jq", ".url");
+        Ok(GhCommand::execute(&cmd, self.executor.as_ref())?
+            .trim()
+            .to_string())
+    }
 }
 
 impl GhCommand for RepoViewCommand {
@@ -230,3 +782,502 @@ impl GhCommand for RepoViewCommand {
         self.cmd.build_args()
     }
 }
+
+/// Command for editing repository settings
+pub struct RepoEditCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl RepoEditCommand {
+    fn new(executor: Arc<GhExecutor>, repo: Option<&str>) -> Self {
+        Self {
+            executor,
+            cmd: with_repo(&["repo", "edit"], repo),
+        }
+    }
+
+    fn toggle(mut self, flag: &str, enabled: bool) -> Self {
+        self.cmd = self.cmd.flag(&format!("{}={}", flag, enabled));
+        self
+    }
+
+    /// Change the visibility
+    ///
+    /// gh refuses this without `.accept_visibility_change_consequences()`.
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.cmd = self.cmd.option("--visibility", visibility.as_str());
+        self
+    }
+
+    /// Acknowledge that changing the visibility may lose stars, watchers and forks
+    pub fn accept_visibility_change_consequences(mut self) -> Self {
+        self.cmd = self.cmd.flag("--accept-visibility-change-consequences");
+        self
+    }
+
+    /// Set the default branch
+    pub fn default_branch(mut self, branch: &str) -> Self {
+        self.cmd = self.cmd.option("--default-branch", branch);
+        self
+    }
+
+    /// Set the description
+    pub fn description(mut self, desc: &str) -> Self {
+        self.cmd = self.cmd.option("--description", desc);
+        self
+    }
+
+    /// Set the homepage URL
+    pub fn homepage(mut self, url: &str) -> Self {
+        self.cmd = self.cmd.option("--homepage", url);
+        self
+    }
+
+    /// Add a topic
+    pub fn add_topic(mut self, topic: &str) -> Self {
+        self.cmd = self.cmd.option("--add-topic", topic);
+        self
+    }
+
+    /// Remove a topic
+    pub fn remove_topic(mut self, topic: &str) -> Self {
+        self.cmd = self.cmd.option("--remove-topic", topic);
+        self
+    }
+
+    /// Allow or disallow merge commits
+    pub fn enable_merge_commit(self, enabled: bool) -> Self {
+        self.toggle("--enable-merge-commit", enabled)
+    }
+
+    /// Allow or disallow squash merging
+    pub fn enable_squash_merge(self, enabled: bool) -> Self {
+        self.toggle("--enable-squash-merge", enabled)
+    }
+
+    /// Allow or disallow rebase merging
+    pub fn enable_rebase_merge(self, enabled: bool) -> Self {
+        self.toggle("--enable-rebase-merge", enabled)
+    }
+
+    /// Allow or disallow auto-merge
+    pub fn enable_auto_merge(self, enabled: bool) -> Self {
+        self.toggle("--enable-auto-merge", enabled)
+    }
+
+    /// Delete head branches when pull requests are merged
+    pub fn delete_branch_on_merge(self, enabled: bool) -> Self {
+        self.toggle("--delete-branch-on-merge", enabled)
+    }
+
+    /// Enable or disable the wiki
+    pub fn enable_wiki(self, enabled: bool) -> Self {
+        self.toggle("--enable-wiki", enabled)
+    }
+
+    /// Enable or disable issues
+    pub fn enable_issues(self, enabled: bool) -> Self {
+        self.toggle("--enable-issues", enabled)
+    }
+
+    /// Enable or disable projects
+    pub fn enable_projects(self, enabled: bool) -> Self {
+        self.toggle("--enable-projects", enabled)
+    }
+
+    /// Enable or disable discussions
+    pub fn enable_discussions(self, enabled: bool) -> Self {
+        self.toggle("--enable-discussions", enabled)
+    }
+
+    /// Allow or disallow forking
+    pub fn allow_forking(self, enabled: bool) -> Self {
+        self.toggle("--allow-forking", enabled)
+    }
+
+    /// Allow or disallow updating pull request branches from the base
+    pub fn allow_update_branch(self, enabled: bool) -> Self {
+        self.toggle("--allow-update-branch", enabled)
+    }
+
+    /// Make the repository available as a template
+    pub fn template(self, enabled: bool) -> Self {
+        self.toggle("--template", enabled)
+    }
+
+    /// Execute the edit command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for RepoEditCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for deleting a repository
+pub struct RepoDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl RepoDeleteCommand {
+    fn new(executor: Arc<GhExecutor>, repo: Option<&str>) -> Self {
+        Self {
+            executor,
+            cmd: with_repo(&["repo", "delete"], repo),
+        }
+    }
+
+    /// Confirm deletion without prompting (required when not interactive)
+    pub fn yes(mut self) -> Self {
+        self.cmd = self.cmd.flag("--yes");
+        self
+    }
+
+    /// Execute the delete command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for RepoDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for archiving or unarchiving a repository
+pub struct RepoArchiveCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl RepoArchiveCommand {
+    fn new(executor: Arc<GhExecutor>, action: &str, repo: Option<&str>) -> Self {
+        Self {
+            executor,
+            cmd: with_repo(&["repo", action], repo),
+        }
+    }
+
+    /// Skip the confirmation prompt (required when not interactive)
+    pub fn yes(mut self) -> Self {
+        self.cmd = self.cmd.flag("--yes");
+        self
+    }
+
+    /// Execute the archive command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for RepoArchiveCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for renaming a repository
+pub struct RepoRenameCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl RepoRenameCommand {
+    fn new(executor: Arc<GhExecutor>, new_name: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["repo", "rename"]).arg(new_name),
+        }
+    }
+
+    /// Rename the given repository instead of the current one
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Skip the confirmation prompt (required when not interactive)
+    pub fn yes(mut self) -> Self {
+        self.cmd = self.cmd.flag("--yes");
+        self
+    }
+
+    /// Execute the rename command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for RepoRenameCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for syncing a repository
+pub struct RepoSyncCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl RepoSyncCommand {
+    fn new(executor: Arc<GhExecutor>, destination: Option<&str>) -> Self {
+        Self {
+            executor,
+            cmd: with_repo(&["repo", "sync"], destination),
+        }
+    }
+
+    /// Branch to sync (defaults to the default branch)
+    pub fn branch(mut self, branch: &str) -> Self {
+        self.cmd = self.cmd.option("--branch", branch);
+        self
+    }
+
+    /// Source repository to sync from (defaults to the parent)
+    pub fn source(mut self, source: &str) -> Self {
+        self.cmd = self.cmd.option("--source", source);
+        self
+    }
+
+    /// Hard reset the destination branch to match the source
+    pub fn force(mut self) -> Self {
+        self.cmd = self.cmd.flag("--force");
+        self
+    }
+
+    /// Execute the sync command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for RepoSyncCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for setting the default repository
+pub struct RepoSetDefaultCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl RepoSetDefaultCommand {
+    fn new(executor: Arc<GhExecutor>, repo: Option<&str>) -> Self {
+        Self {
+            executor,
+            cmd: with_repo(&["repo", "set-default"], repo),
+        }
+    }
+
+    /// Show the current default repository instead of setting it
+    pub fn view(mut self) -> Self {
+        self.cmd = self.cmd.flag("--view");
+        self
+    }
+
+    /// Unset the current default repository
+    pub fn unset(mut self) -> Self {
+        self.cmd = self.cmd.flag("--unset");
+        self
+    }
+
+    /// Execute the set-default command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for RepoSetDefaultCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repo_name() {
+        assert_eq!(repo_name("cli/cli"), "cli");
+        assert_eq!(repo_name("gh-cli-rs"), "gh-cli-rs");
+        assert_eq!(repo_name("https://github.com/cli/cli.git"), "cli");
+        assert_eq!(repo_name("git@github.com:cli/go-gh.git"), "go-gh");
+        assert_eq!(repo_name("https://github.com/cli/cli/"), "cli");
+    }
+
+    #[test]
+    fn test_fork_args() {
+        assert_eq!(repo_full_name("cli/cli"), "cli/cli");
+        assert_eq!(repo_full_name("https://github.com/cli/cli.git"), "cli/cli");
+        assert_eq!(repo_full_name("git@github.com:cli/go-gh.git"), "cli/go-gh");
+
+        let cmd = RepoForkCommand::new(Arc::new(GhExecutor::default()), "cli/cli")
+            .org("octo")
+            .fork_name("gh")
+            .default_branch_only()
+            .clone()
+            .remote_name("fork");
+        assert_eq!(
+            cmd.build_args(),
+            vec![
+                "api",
+                "-X",
+                "POST",
+                "repos/cli/cli/forks",
+                "-f",
+                "organization=octo",
+                "-f",
+                "name=gh",
+                "-F",
+                "default_branch_only=true"
+            ]
+        );
+        assert_eq!(
+            cmd.cmd.build_args(),
+            vec![
+                "repo",
+                "fork",
+                "cli/cli",
+                "--org",
+                "octo",
+                "--clone",
+                "--remote-name",
+                "fork"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clone_args() {
+        let cmd = RepoCloneCommand::new(Arc::new(GhExecutor::default()), "cli/cli")
+            .directory("work/cli")
+            .upstream_remote_name("parent")
+            .git_flags(&["--depth", "1"]);
+        assert_eq!(
+            cmd.build_args(),
+            vec![
+                "repo",
+                "clone",
+                "cli/cli",
+                "--upstream-remote-name",
+                "parent",
+                "work/cli",
+                "--",
+                "--depth",
+                "1"
+            ]
+        );
+        assert_eq!(cmd.local_path(), PathBuf::from("work/cli"));
+    }
+
+    #[test]
+    fn test_edit_args() {
+        let executor = Arc::new(GhExecutor::default());
+        let cmd = RepoEditCommand::new(executor.clone(), Some("octo/app"))
+            .visibility(Visibility::Private)
+            .enable_wiki(false)
+            .add_topic("rust");
+        assert_eq!(
+            cmd.build_args(),
+            vec![
+                "repo",
+                "edit",
+                "octo/app",
+                "--visibility",
+                "private",
+                "--enable-wiki=false",
+                "--add-topic",
+                "rust"
+            ]
+        );
+
+        let cmd = RepoEditCommand::new(executor, None)
+            .visibility(Visibility::Public)
+            .accept_visibility_change_consequences();
+        assert_eq!(
+            cmd.build_args(),
+            vec![
+                "repo",
+                "edit",
+                "--visibility",
+                "public",
+                "--accept-visibility-change-consequences"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lifecycle_args() {
+        let executor = Arc::new(GhExecutor::default());
+        assert_eq!(
+            RepoDeleteCommand::new(executor.clone(), Some("octo/old"))
+                .yes()
+                .build_args(),
+            vec!["repo", "delete", "octo/old", "--yes"]
+        );
+        assert_eq!(
+            RepoArchiveCommand::new(executor.clone(), "unarchive", None)
+                .yes()
+                .build_args(),
+            vec!["repo", "unarchive", "--yes"]
+        );
+        assert_eq!(
+            RepoRenameCommand::new(executor.clone(), "new-name")
+                .repo("octo/app")
+                .yes()
+                .build_args(),
+            vec!["repo", "rename", "new-name", "--repo", "octo/app", "--yes"]
+        );
+        assert_eq!(
+            RepoSyncCommand::new(executor.clone(), Some("me/app"))
+                .branch("main")
+                .source("octo/app")
+                .force()
+                .build_args(),
+            vec!["repo", "sync", "me/app", "--branch", "main", "--source", "octo/app", "--force"]
+        );
+        assert_eq!(
+            RepoSetDefaultCommand::new(executor, None)
+                .view()
+                .build_args(),
+            vec!["repo", "set-default", "--view"]
+        );
+    }
+
+    #[test]
+    fn test_repository_detail_decoding() {
+        let json = r#"{
+            "name": "cli", "nameWithOwner": "cli/cli", "owner": {"id": "1", "login": "cli"},
+            "description": "GitHub CLI", "url": "https://github.com/cli/cli", "homepageUrl": "",
+            "defaultBranchRef": {"name": "trunk"}, "primaryLanguage": {"name": "Go"},
+            "languages": [{"size": 100, "node": {"name": "Go"}}],
+            "licenseInfo": {"key": "mit", "name": "MIT License", "nickname": ""},
+            "repositoryTopics": [{"name": "cli"}, {"name": "git"}],
+            "stargazerCount": 10, "forkCount": 2, "watchers": {"totalCount": 3},
+            "visibility": "PUBLIC", "isArchived": false, "isFork": false, "isTemplate": false,
+            "hasIssuesEnabled": true, "hasWikiEnabled": false,
+            "createdAt": "2019-10-03T16:46:09Z", "updatedAt": "2024-01-01T00:00:00Z",
+            "pushedAt": "2024-01-01T00:00:00Z"
+        }"#;
+        let detail: RepositoryDetail = serde_json::from_str(json).unwrap();
+
+        assert_eq!(detail.owner, "cli");
+        assert_eq!(detail.default_branch, "trunk");
+        assert_eq!(detail.primary_language.as_deref(), Some("Go"));
+        assert_eq!(detail.languages[0].size, 100);
+        assert_eq!(detail.license.unwrap().key, "mit");
+        assert_eq!(detail.topics, vec!["cli", "git"]);
+        assert_eq!(detail.watchers, 3);
+        assert_eq!(detail.visibility, Visibility::Public);
+    }
+}
@@ -0,0 +1,469 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::api::{paginated, percent_encode};
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Ruleset commands namespace
+///
+/// `gh ruleset` has no JSON output, so `fetch()` reads the same data from the
+/// rulesets API. Without `.repo()` or `.org()` the current repository is used.
+#[derive(Clone)]
+pub struct RulesetCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl RulesetCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List rulesets
+    pub fn list(&self) -> RulesetListCommand {
+        RulesetListCommand::new(self.executor.clone())
+    }
+
+    /// View a ruleset by ID
+    pub fn view(&self, id: u64) -> RulesetViewCommand {
+        RulesetViewCommand::new(self.executor.clone(), id)
+    }
+
+    /// Show the rules that apply to a branch
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::GhClient;
+    /// let client = GhClient::new();
+    /// let rules = client.ruleset().check("main").repo("octo/app").fetch();
+    /// ```
+    pub fn check(&self, branch: &str) -> RulesetCheckCommand {
+        RulesetCheckCommand::new(self.executor.clone(), branch)
+    }
+}
+
+/// Scope shared by the ruleset builders
+#[derive(Debug, Clone, Default)]
+struct Scope {
+    repo: Option<String>,
+    org: Option<String>,
+}
+
+impl Scope {
+    fn endpoint(&self, path: &str) -> String {
+        match (&self.org, &self.repo) {
+            (Some(org), _) => format!("orgs/{}/{}", org, path),
+            (None, Some(repo)) => format!("repos/{}/{}", repo, path),
+            (None, None) => format!("repos/{{owner}}/{{repo}}/{}", path),
+        }
+    }
+}
+
+fn api_args(endpoint: String) -> Vec<String> {
+    vec!["api".to_string(), endpoint]
+}
+
+/// How a ruleset is enforced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RulesetEnforcement {
+    Disabled,
+    Active,
+    /// Evaluate mode: rules are reported but not enforced
+    Evaluate,
+    #[serde(other)]
+    Unknown,
+}
+
+/// What a ruleset applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RulesetTarget {
+    Branch,
+    Tag,
+    Push,
+    #[serde(other)]
+    Unknown,
+}
+
+/// When a bypass actor may bypass the ruleset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BypassMode {
+    Always,
+    PullRequest,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Actor allowed to bypass a ruleset
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BypassActor {
+    /// Absent for actor types without an ID (e.g. `OrganizationAdmin`)
+    #[serde(default)]
+    pub actor_id: Option<u64>,
+    /// `RepositoryRole`, `Team`, `Integration`, `OrganizationAdmin` or `DeployKey`
+    pub actor_type: String,
+    pub bypass_mode: BypassMode,
+}
+
+/// Ref name patterns a ruleset targets (e.g. `~DEFAULT_BRANCH`, `refs/heads/release/*`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct RefNameCondition {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// Conditions selecting the refs (and, for organization rulesets, repositories) a ruleset targets
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct RulesetConditions {
+    #[serde(default)]
+    pub ref_name: Option<RefNameCondition>,
+    /// Other conditions such as `repository_name` or `repository_property`
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+/// A rule in a ruleset
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Rule {
+    /// Rule type (e.g. `pull_request`, `required_status_checks`, `non_fast_forward`)
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub parameters: Option<serde_json::Value>,
+}
+
+/// A ruleset returned by `RulesetListCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RulesetSummary {
+    pub id: u64,
+    pub name: String,
+    #[serde(default = "unknown_target")]
+    pub target: RulesetTarget,
+    /// `Repository` or `Organization`
+    pub source_type: String,
+    /// Repository or organization the ruleset is defined in
+    pub source: String,
+    pub enforcement: RulesetEnforcement,
+}
+
+fn unknown_target() -> RulesetTarget {
+    RulesetTarget::Unknown
+}
+
+/// Ruleset details returned by `RulesetViewCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Ruleset {
+    pub id: u64,
+    pub name: String,
+    #[serde(default = "unknown_target")]
+    pub target: RulesetTarget,
+    pub source_type: String,
+    pub source: String,
+    pub enforcement: RulesetEnforcement,
+    #[serde(default)]
+    pub bypass_actors: Vec<BypassActor>,
+    #[serde(default)]
+    pub conditions: Option<RulesetConditions>,
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+    #[serde(default)]
+    pub created_at: Option<Timestamp>,
+    #[serde(default)]
+    pub updated_at: Option<Timestamp>,
+}
+
+/// A rule in effect for a branch, returned by `RulesetCheckCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BranchRule {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub parameters: Option<serde_json::Value>,
+    pub ruleset_id: u64,
+    pub ruleset_source_type: String,
+    pub ruleset_source: String,
+}
+
+/// Command for listing rulesets
+pub struct RulesetListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    scope: Scope,
+    parents: bool,
+    limit: Option<u32>,
+}
+
+impl RulesetListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["ruleset", "list"]),
+            scope: Scope::default(),
+            parents: true,
+            limit: None,
+        }
+    }
+
+    /// List rulesets of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self.scope.repo = Some(repo.to_string());
+        self
+    }
+
+    /// List rulesets of an organization
+    pub fn org(mut self, org: &str) -> Self {
+        self.cmd = self.cmd.option("--org", org);
+        self.scope.org = Some(org.to_string());
+        self
+    }
+
+    /// Include rulesets configured at higher levels (default true)
+    pub fn parents(mut self, parents: bool) -> Self {
+        self.cmd = self.cmd.flag(&format!("--parents={}", parents));
+        self.parents = parents;
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Fetch and parse the rulesets
+    pub fn fetch(&self) -> Result<Vec<RulesetSummary>> {
+        let parents = self.parents.to_string();
+        let query: &[(&str, &str)] = if self.scope.org.is_none() {
+            &[("includes_parents", &parents)]
+        } else {
+            &[]
+        };
+        let mut rulesets: Vec<RulesetSummary> = self
+            .executor
+            .execute_json_pages(&paginated(&self.scope.endpoint("rulesets"), query))?;
+        if let Some(limit) = self.limit {
+            rulesets.truncate(limit as usize);
+        }
+        Ok(rulesets)
+    }
+}
+
+impl GhCommand for RulesetListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for viewing a ruleset
+pub struct RulesetViewCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    id: u64,
+    scope: Scope,
+}
+
+impl RulesetViewCommand {
+    fn new(executor: Arc<GhExecutor>, id: u64) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["ruleset", "view"]).arg(&id.to_string()),
+            id,
+            scope: Scope::default(),
+        }
+    }
+
+    /// View a ruleset of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self.scope.repo = Some(repo.to_string());
+        self
+    }
+
+    /// View an organization ruleset
+    pub fn org(mut self, org: &str) -> Self {
+        self.cmd = self.cmd.option("--org", org);
+        self.scope.org = Some(org.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Fetch and parse the ruleset with its rules and bypass actors
+    pub fn fetch(&self) -> Result<Ruleset> {
+        let endpoint = self.scope.endpoint(&format!("rulesets/{}", self.id));
+        self.executor.execute_json(&api_args(endpoint))
+    }
+}
+
+impl GhCommand for RulesetViewCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for checking the rules in effect for a branch
+pub struct RulesetCheckCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    branch: String,
+    scope: Scope,
+}
+
+impl RulesetCheckCommand {
+    fn new(executor: Arc<GhExecutor>, branch: &str) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["ruleset", "check"]).arg(branch),
+            branch: branch.to_string(),
+            scope: Scope::default(),
+        }
+    }
+
+    /// Check a branch of the given repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self.scope.repo = Some(repo.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Fetch and parse the effective rules, including those from parent rulesets
+    pub fn fetch(&self) -> Result<Vec<BranchRule>> {
+        let endpoint = self
+            .scope
+            .endpoint(&format!("rules/branches/{}", percent_encode(&self.branch)));
+        self.executor.execute_json_pages(&paginated(&endpoint, &[]))
+    }
+}
+
+impl GhCommand for RulesetCheckCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+
+    #[test]
+    fn test_scope_endpoint() {
+        let scope = Scope::default();
+        assert_eq!(scope.endpoint("rulesets"), "repos/{owner}/{repo}/rulesets");
+        let scope = Scope {
+            repo: Some("octo/app".to_string()),
+            org: None,
+        };
+        assert_eq!(scope.endpoint("rulesets/7"), "repos/octo/app/rulesets/7");
+        let scope = Scope {
+            repo: None,
+            org: Some("octo".to_string()),
+        };
+        assert_eq!(scope.endpoint("rulesets"), "orgs/octo/rulesets");
+    }
+
+    #[test]
+    fn test_ruleset_decoding() {
+        let json = r#"{
+            "id": 42, "name": "main protection", "target": "branch",
+            "source_type": "Repository", "source": "octo/app", "enforcement": "active",
+            "bypass_actors": [{"actor_id": null, "actor_type": "OrganizationAdmin", "bypass_mode": "always"}],
+            "conditions": {"ref_name": {"include": ["~DEFAULT_BRANCH"], "exclude": []}},
+            "rules": [{"type": "deletion"},
+                      {"type": "pull_request", "parameters": {"required_approving_review_count": 2}}]
+        }"#;
+        let ruleset: Ruleset = serde_json::from_str(json).unwrap();
+        assert_eq!(ruleset.enforcement, RulesetEnforcement::Active);
+        assert_eq!(ruleset.bypass_actors[0].bypass_mode, BypassMode::Always);
+        assert_eq!(
+            ruleset.conditions.unwrap().ref_name.unwrap().include,
+            vec!["~DEFAULT_BRANCH"]
+        );
+        assert_eq!(ruleset.rules[1].kind, "pull_request");
+    }
+
+    fn replaying(args: &[&str], stdout: &str) -> Arc<GhExecutor> {
+        Arc::new(
+            GhExecutor::default().with_cassette(Cassette::from_interactions(vec![Interaction {
+                args: args.iter().map(|s| s.to_string()).collect(),
+                env: BTreeMap::new(),
+                stdin: None,
+                stdout: stdout.to_string(),
+                stderr: String::new(),
+                exit_code: 0,
+            }])),
+        )
+    }
+
+    #[test]
+    fn test_list_fetch_reads_pages_up_to_limit() {
+        let ruleset = |id: u64| {
+            format!(
+                r#"{{"id": {}, "name": "r{}", "target": "branch", "source_type": "Repository", "source": "octo/app", "enforcement": "active"}}"#,
+                id, id
+            )
+        };
+        let page = |ids: std::ops::Range<u64>| {
+            format!("[{}]", ids.map(ruleset).collect::<Vec<_>>().join(","))
+        };
+        let executor = replaying(
+            &[
+                "api",
+                "--paginate",
+                "repos/octo/app/rulesets?per_page=100&includes_parents=true",
+            ],
+            &format!("{}\n{}\n", page(0..100), page(100..150)),
+        );
+
+        let rulesets = RulesetListCommand::new(executor)
+            .repo("octo/app")
+            .limit(120)
+            .fetch()
+            .unwrap();
+        assert_eq!(rulesets.len(), 120);
+        assert_eq!(rulesets[119].id, 119);
+    }
+
+    #[test]
+    fn test_check_fetch_encodes_branch() {
+        assert_eq!(
+            percent_encode("release/1.x+hotfix"),
+            "release%2F1.x%2Bhotfix"
+        );
+
+        let executor = replaying(
+            &[
+                "api",
+                "--paginate",
+                "repos/octo/app/rules/branches/release%2F1.x?per_page=100",
+            ],
+            r#"[{"type": "deletion", "ruleset_id": 42, "ruleset_source_type": "Repository", "ruleset_source": "octo/app"}]"#,
+        );
+
+        let rules = RulesetCheckCommand::new(executor, "release/1.x")
+            .repo("octo/app")
+            .fetch()
+            .unwrap();
+        assert_eq!(rules[0].ruleset_id, 42);
+    }
+}
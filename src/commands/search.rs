@@ -0,0 +1,886 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::issue::IssueState;
+use crate::commands::repo::{License, Visibility};
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use crate::types::{Actor, Label, Timestamp};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::sync::Arc;
+
+/// Search commands namespace
+#[derive(Clone)]
+pub struct SearchCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl SearchCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// Search repositories
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::{GhClient, QueryRange, RepoSort};
+    /// let client = GhClient::new();
+    /// let repos = client
+    ///     .search()
+    ///     .repos("cli")
+    ///     .language("rust")
+    ///     .stars(QueryRange::at_least(100))
+    ///     .sort(RepoSort::Stars)
+    ///     .fetch();
+    /// ```
+    pub fn repos(&self, query: &str) -> SearchReposCommand {
+        SearchReposCommand::new(self.executor.clone(), query)
+    }
+
+    /// Search issues
+    pub fn issues(&self, query: &str) -> SearchIssuesCommand {
+        SearchIssuesCommand::new(self.executor.clone(), "issues", query)
+    }
+
+    /// Search pull requests
+    pub fn prs(&self, query: &str) -> SearchIssuesCommand {
+        SearchIssuesCommand::new(self.executor.clone(), "prs", query)
+    }
+
+    /// Search commits
+    pub fn commits(&self, query: &str) -> SearchCommitsCommand {
+        SearchCommitsCommand::new(self.executor.clone(), query)
+    }
+
+    /// Search code
+    pub fn code(&self, query: &str) -> SearchCodeCommand {
+        SearchCodeCommand::new(self.executor.clone(), query)
+    }
+}
+
+/// Range qualifier for dates ("2024-01-31") and numbers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryRange(String);
+
+impl QueryRange {
+    /// Greater than the value
+    pub fn after(value: impl Display) -> Self {
+        Self(format!(">{}", value))
+    }
+
+    /// Greater than or equal to the value
+    pub fn at_least(value: impl Display) -> Self {
+        Self(format!(">={}", value))
+    }
+
+    /// Less than the value
+    pub fn before(value: impl Display) -> Self {
+        Self(format!("<{}", value))
+    }
+
+    /// Less than or equal to the value
+    pub fn at_most(value: impl Display) -> Self {
+        Self(format!("<={}", value))
+    }
+
+    /// Between two values, inclusive
+    pub fn between(from: impl Display, to: impl Display) -> Self {
+        Self(format!("{}..{}", from, to))
+    }
+
+    /// Exactly the value
+    pub fn exactly(value: impl Display) -> Self {
+        Self(value.to_string())
+    }
+
+    /// Qualifier as passed to gh
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Sort direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+}
+
+/// State filter for issue and pull request search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchState {
+    Open,
+    Closed,
+}
+
+impl SearchState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SearchState::Open => "open",
+            SearchState::Closed => "closed",
+        }
+    }
+}
+
+/// Sort field for repository search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoSort {
+    Forks,
+    HelpWantedIssues,
+    Stars,
+    Updated,
+}
+
+impl RepoSort {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RepoSort::Forks => "forks",
+            RepoSort::HelpWantedIssues => "help-wanted-issues",
+            RepoSort::Stars => "stars",
+            RepoSort::Updated => "updated",
+        }
+    }
+}
+
+/// Sort field for issue and pull request search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSort {
+    Comments,
+    Created,
+    Interactions,
+    Reactions,
+    Updated,
+}
+
+impl IssueSort {
+    fn as_str(&self) -> &'static str {
+        match self {
+            IssueSort::Comments => "comments",
+            IssueSort::Created => "created",
+            IssueSort::Interactions => "interactions",
+            IssueSort::Reactions => "reactions",
+            IssueSort::Updated => "updated",
+        }
+    }
+}
+
+/// Sort field for commit search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitSort {
+    AuthorDate,
+    CommitterDate,
+}
+
+impl CommitSort {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CommitSort::AuthorDate => "author-date",
+            CommitSort::CommitterDate => "committer-date",
+        }
+    }
+}
+
+/// Start a search command with an optional query
+fn search(kind: &str, query: &str) -> BaseCommand {
+    let cmd = BaseCommand::with_subcommands(&["search", kind]);
+    if query.is_empty() {
+        cmd
+    } else {
+        cmd.arg(query)
+    }
+}
+
+/// Repository a search result belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchRepository {
+    /// Empty for code search results, which only report `name_with_owner`
+    #[serde(default)]
+    pub name: String,
+    /// Exported as `fullName` for commit search results
+    #[serde(alias = "fullName")]
+    pub name_with_owner: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub is_fork: bool,
+    #[serde(default)]
+    pub is_private: bool,
+}
+
+/// Fields requested by `SearchReposCommand::fetch`
+const REPO_SEARCH_FIELDS: &[&str] = &[
+    "fullName",
+    "name",
+    "owner",
+    "description",
+    "url",
+    "language",
+    "license",
+    "stargazersCount",
+    "forksCount",
+    "openIssuesCount",
+    "visibility",
+    "isArchived",
+    "isFork",
+    "createdAt",
+    "updatedAt",
+    "pushedAt",
+];
+
+/// A repository returned by `SearchReposCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoSearchResult {
+    pub full_name: String,
+    pub name: String,
+    pub owner: Actor,
+    #[serde(default)]
+    pub description: String,
+    pub url: String,
+    #[serde(default)]
+    pub language: String,
+    #[serde(default)]
+    pub license: Option<License>,
+    pub stargazers_count: u64,
+    pub forks_count: u64,
+    pub open_issues_count: u64,
+    pub visibility: Visibility,
+    pub is_archived: bool,
+    pub is_fork: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    #[serde(default)]
+    pub pushed_at: Option<Timestamp>,
+}
+
+/// Command for searching repositories
+pub struct SearchReposCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl SearchReposCommand {
+    fn new(executor: Arc<GhExecutor>, query: &str) -> Self {
+        Self {
+            executor,
+            cmd: search("repos", query),
+        }
+    }
+
+    /// Filter by owner
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = self.cmd.option("--owner", owner);
+        self
+    }
+
+    /// Filter by language
+    pub fn language(mut self, language: &str) -> Self {
+        self.cmd = self.cmd.option("--language", language);
+        self
+    }
+
+    /// Filter by topic
+    pub fn topic(mut self, topic: &str) -> Self {
+        self.cmd = self.cmd.option("--topic", topic);
+        self
+    }
+
+    /// Filter by visibility
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.cmd = self.cmd.option("--visibility", visibility.as_str());
+        self
+    }
+
+    /// Filter by archived state
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.cmd = self.cmd.flag(&format!("--archived={}", archived));
+        self
+    }
+
+    /// Filter by number of stars
+    pub fn stars(mut self, range: QueryRange) -> Self {
+        self.cmd = self.cmd.option("--stars", range.as_str());
+        self
+    }
+
+    /// Filter by creation date
+    pub fn created(mut self, range: QueryRange) -> Self {
+        self.cmd = self.cmd.option("--created", range.as_str());
+        self
+    }
+
+    /// Filter by last update date
+    pub fn updated(mut self, range: QueryRange) -> Self {
+        self.cmd = self.cmd.option("--updated", range.as_str());
+        self
+    }
+
+    /// Sort results
+    pub fn sort(mut self, sort: RepoSort) -> Self {
+        self.cmd = self.cmd.option("--sort", sort.as_str());
+        self
+    }
+
+    /// Set the sort direction
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.cmd = self.cmd.option("--order", order.as_str());
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the matching repositories
+    pub fn fetch(&self) -> Result<Vec<RepoSearchResult>> {
+        let cmd = self
+            .cmd
+            .clone()
+            .option("--json", &REPO_SEARCH_FIELDS.join(","));
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for SearchReposCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Fields requested by `SearchIssuesCommand::fetch`
+pub(crate) const ISSUE_SEARCH_FIELDS: &[&str] = &[
+    "number",
+    "title",
+    "body",
+    "state",
+    "url",
+    "author",
+    "labels",
+    "assignees",
+    "commentsCount",
+    "repository",
+    "isPullRequest",
+    "createdAt",
+    "updatedAt",
+    "closedAt",
+];
+
+/// An issue or pull request returned by `SearchIssuesCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchResult {
+    pub number: u32,
+    pub title: String,
+    #[serde(default)]
+    pub body: String,
+    pub state: IssueState,
+    pub url: String,
+    pub author: Actor,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub assignees: Vec<Actor>,
+    #[serde(default)]
+    pub comments_count: u64,
+    pub repository: SearchRepository,
+    #[serde(default)]
+    pub is_pull_request: bool,
+    /// Only set when searching pull requests
+    #[serde(default)]
+    pub is_draft: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    #[serde(default)]
+    pub closed_at: Option<Timestamp>,
+}
+
+/// Command for searching issues or pull requests
+pub struct SearchIssuesCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    prs: bool,
+    excluded_repos: Vec<String>,
+}
+
+impl SearchIssuesCommand {
+    fn new(executor: Arc<GhExecutor>, kind: &str, query: &str) -> Self {
+        Self {
+            executor,
+            cmd: search(kind, query),
+            prs: kind == "prs",
+            excluded_repos: Vec::new(),
+        }
+    }
+
+    /// Filter by repository owner
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = self.cmd.option("--owner", owner);
+        self
+    }
+
+    /// Filter by repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Leave out results from a repository (`-repo:` qualifier)
+    pub fn exclude_repo(mut self, repo: &str) -> Self {
+        self.excluded_repos.push(repo.to_string());
+        self
+    }
+
+    /// Filter by repository language
+    pub fn language(mut self, language: &str) -> Self {
+        self.cmd = self.cmd.option("--language", language);
+        self
+    }
+
+    /// Filter by state
+    pub fn state(mut self, state: SearchState) -> Self {
+        self.cmd = self.cmd.option("--state", state.as_str());
+        self
+    }
+
+    /// Filter by label
+    pub fn label(mut self, label: &str) -> Self {
+        self.cmd = self.cmd.option("--label", label);
+        self
+    }
+
+    /// Filter by author
+    pub fn author(mut self, author: &str) -> Self {
+        self.cmd = self.cmd.option("--author", author);
+        self
+    }
+
+    /// Filter by assignee
+    pub fn assignee(mut self, assignee: &str) -> Self {
+        self.cmd = self.cmd.option("--assignee", assignee);
+        self
+    }
+
+    /// Filter pull requests by requested reviewer (`@me` for the current user)
+    pub fn review_requested(mut self, user: &str) -> Self {
+        self.cmd = self.cmd.option("--review-requested", user);
+        self
+    }
+
+    /// Filter by creation date
+    pub fn created(mut self, range: QueryRange) -> Self {
+        self.cmd = self.cmd.option("--created", range.as_str());
+        self
+    }
+
+    /// Filter by last update date
+    pub fn updated(mut self, range: QueryRange) -> Self {
+        self.cmd = self.cmd.option("--updated", range.as_str());
+        self
+    }
+
+    /// Filter by closing date
+    pub fn closed(mut self, range: QueryRange) -> Self {
+        self.cmd = self.cmd.option("--closed", range.as_str());
+        self
+    }
+
+    /// Filter pull requests by merge date
+    pub fn merged_at(mut self, range: QueryRange) -> Self {
+        self.cmd = self.cmd.option("--merged-at", range.as_str());
+        self
+    }
+
+    /// Filter pull requests by base branch
+    pub fn base(mut self, branch: &str) -> Self {
+        self.cmd = self.cmd.option("--base", branch);
+        self
+    }
+
+    /// Filter pull requests by review status (none, required, approved, changes_requested)
+    pub fn review(mut self, review: &str) -> Self {
+        self.cmd = self.cmd.option("--review", review);
+        self
+    }
+
+    /// Filter pull requests by draft state
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.cmd = self.cmd.flag(&format!("--draft={}", draft));
+        self
+    }
+
+    /// Sort results
+    pub fn sort(mut self, sort: IssueSort) -> Self {
+        self.cmd = self.cmd.option("--sort", sort.as_str());
+        self
+    }
+
+    /// Set the sort direction
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.cmd = self.cmd.option("--order", order.as_str());
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the matching issues or pull requests
+    pub fn fetch(&self) -> Result<Vec<IssueSearchResult>> {
+        let mut fields = ISSUE_SEARCH_FIELDS.join(",");
+        if self.prs {
+            fields.push_str(",isDraft");
+        }
+        let cmd = self.cmd.clone().option("--json", &fields);
+        self.executor.execute_json(&self.with_exclusions(cmd))
+    }
+
+    /// Arguments of `cmd` followed by the negative qualifiers, which must come
+    /// after `--` so that gh doesn't parse them as flags
+    fn with_exclusions(&self, cmd: BaseCommand) -> Vec<String> {
+        let mut args = cmd.build_args();
+        if !self.excluded_repos.is_empty() {
+            args.push("--".to_string());
+            args.extend(
+                self.excluded_repos
+                    .iter()
+                    .map(|repo| format!("-repo:{}", repo)),
+            );
+        }
+        args
+    }
+}
+
+impl GhCommand for SearchIssuesCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.with_exclusions(self.cmd.clone())
+    }
+}
+
+/// Name, email and date recorded by git
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitSignature {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub email: String,
+    pub date: Timestamp,
+}
+
+/// Git data of a commit search result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitInfo {
+    pub message: String,
+    pub author: GitSignature,
+    pub committer: GitSignature,
+}
+
+/// Fields requested by `SearchCommitsCommand::fetch`
+const COMMIT_SEARCH_FIELDS: &[&str] = &["sha", "url", "commit", "author", "repository"];
+
+/// A commit returned by `SearchCommitsCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CommitSearchResult {
+    pub sha: String,
+    pub url: String,
+    pub commit: CommitInfo,
+    /// GitHub user matching the commit author, if any
+    #[serde(default)]
+    pub author: Option<Actor>,
+    pub repository: SearchRepository,
+}
+
+/// Command for searching commits
+pub struct SearchCommitsCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl SearchCommitsCommand {
+    fn new(executor: Arc<GhExecutor>, query: &str) -> Self {
+        Self {
+            executor,
+            cmd: search("commits", query),
+        }
+    }
+
+    /// Filter by repository owner
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = self.cmd.option("--owner", owner);
+        self
+    }
+
+    /// Filter by repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Filter by author login
+    pub fn author(mut self, author: &str) -> Self {
+        self.cmd = self.cmd.option("--author", author);
+        self
+    }
+
+    /// Filter by committer login
+    pub fn committer(mut self, committer: &str) -> Self {
+        self.cmd = self.cmd.option("--committer", committer);
+        self
+    }
+
+    /// Filter by authored date
+    pub fn author_date(mut self, range: QueryRange) -> Self {
+        self.cmd = self.cmd.option("--author-date", range.as_str());
+        self
+    }
+
+    /// Filter by committed date
+    pub fn committer_date(mut self, range: QueryRange) -> Self {
+        self.cmd = self.cmd.option("--committer-date", range.as_str());
+        self
+    }
+
+    /// Filter by commit hash
+    pub fn hash(mut self, hash: &str) -> Self {
+        self.cmd = self.cmd.option("--hash", hash);
+        self
+    }
+
+    /// Only merge commits (`true`) or only non-merge commits (`false`)
+    pub fn merge(mut self, merge: bool) -> Self {
+        self.cmd = self.cmd.flag(&format!("--merge={}", merge));
+        self
+    }
+
+    /// Sort results
+    pub fn sort(mut self, sort: CommitSort) -> Self {
+        self.cmd = self.cmd.option("--sort", sort.as_str());
+        self
+    }
+
+    /// Set the sort direction
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.cmd = self.cmd.option("--order", order.as_str());
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the matching commits
+    pub fn fetch(&self) -> Result<Vec<CommitSearchResult>> {
+        let cmd = self
+            .cmd
+            .clone()
+            .option("--json", &COMMIT_SEARCH_FIELDS.join(","));
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for SearchCommitsCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// A fragment of a file matching the query
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextMatch {
+    pub fragment: String,
+}
+
+/// Fields requested by `SearchCodeCommand::fetch`
+const CODE_SEARCH_FIELDS: &[&str] = &["path", "sha", "url", "repository", "textMatches"];
+
+/// A file returned by `SearchCodeCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeSearchResult {
+    pub path: String,
+    pub sha: String,
+    pub url: String,
+    pub repository: SearchRepository,
+    #[serde(default)]
+    pub text_matches: Vec<TextMatch>,
+}
+
+/// Command for searching code
+pub struct SearchCodeCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl SearchCodeCommand {
+    fn new(executor: Arc<GhExecutor>, query: &str) -> Self {
+        Self {
+            executor,
+            cmd: search("code", query),
+        }
+    }
+
+    /// Filter by repository owner
+    pub fn owner(mut self, owner: &str) -> Self {
+        self.cmd = self.cmd.option("--owner", owner);
+        self
+    }
+
+    /// Filter by repository
+    pub fn repo(mut self, repo: &str) -> Self {
+        self.cmd = self.cmd.option("--repo", repo);
+        self
+    }
+
+    /// Filter by language
+    pub fn language(mut self, language: &str) -> Self {
+        self.cmd = self.cmd.option("--language", language);
+        self
+    }
+
+    /// Filter by file extension
+    pub fn extension(mut self, extension: &str) -> Self {
+        self.cmd = self.cmd.option("--extension", extension);
+        self
+    }
+
+    /// Filter by file name
+    pub fn filename(mut self, filename: &str) -> Self {
+        self.cmd = self.cmd.option("--filename", filename);
+        self
+    }
+
+    /// Limit the number of results
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cmd = self.cmd.option("--limit", &limit.to_string());
+        self
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Execute and parse the matching files
+    pub fn fetch(&self) -> Result<Vec<CodeSearchResult>> {
+        let cmd = self
+            .cmd
+            .clone()
+            .option("--json", &CODE_SEARCH_FIELDS.join(","));
+        self.executor.execute_json(&cmd.build_args())
+    }
+}
+
+impl GhCommand for SearchCodeCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fixtures follow the `--json` output of gh 2.x
+
+    #[test]
+    fn test_repo_search_decoding() {
+        let json = r#"[{"createdAt": "2019-10-03T16:46:09Z", "description": "GitHub CLI",
+            "forksCount": 5000, "fullName": "cli/cli", "isArchived": false, "isFork": false,
+            "language": "Go", "license": {"key": "mit", "name": "MIT License", "url": ""},
+            "name": "cli", "openIssuesCount": 700,
+            "owner": {"id": "O_1", "is_bot": false, "login": "cli", "type": "Organization", "url": ""},
+            "pushedAt": "2024-05-01T10:00:00Z", "stargazersCount": 36000,
+            "updatedAt": "2024-05-01T10:00:00Z", "url": "https://github.com/cli/cli",
+            "visibility": "public"}]"#;
+        let repos: Vec<RepoSearchResult> = serde_json::from_str(json).unwrap();
+        assert_eq!(repos[0].full_name, "cli/cli");
+        assert_eq!(repos[0].owner.login, "cli");
+        assert_eq!(repos[0].visibility, Visibility::Public);
+        assert_eq!(repos[0].license.as_ref().unwrap().key, "mit");
+    }
+
+    #[test]
+    fn test_issue_and_pr_search_decoding() {
+        let json = r#"[{"assignees": [], "author": {"id": "U_1", "is_bot": false, "login": "monalisa",
+            "type": "User", "url": ""}, "body": "", "closedAt": "0001-01-01T00:00:00Z",
+            "commentsCount": 2, "createdAt": "2024-05-01T10:00:00Z", "isDraft": true,
+            "isPullRequest": true, "labels": [{"id": "L_1", "name": "bug", "description": "",
+            "color": "d73a4a"}], "number": 42, "repository": {"name": "cli",
+            "nameWithOwner": "cli/cli"}, "state": "open", "title": "Fix pager",
+            "updatedAt": "2024-05-02T10:00:00Z", "url": "https://github.com/cli/cli/pull/42"}]"#;
+        let prs: Vec<IssueSearchResult> = serde_json::from_str(json).unwrap();
+        assert_eq!(prs[0].number, 42);
+        assert_eq!(prs[0].state, IssueState::Open);
+        assert_eq!(prs[0].repository.name_with_owner, "cli/cli");
+        assert_eq!(prs[0].labels[0].name, "bug");
+        assert!(prs[0].is_pull_request && prs[0].is_draft);
+    }
+
+    #[test]
+    fn test_commit_search_decoding() {
+        let json = r#"[{"author": {"id": "U_1", "is_bot": false, "login": "monalisa", "type": "User",
+            "url": ""}, "commit": {"author": {"date": "2024-05-01T10:00:00Z",
+            "email": "mona@github.com", "name": "Mona"}, "comment_count": 0,
+            "committer": {"date": "2024-05-01T10:00:00Z", "email": "noreply@github.com",
+            "name": "GitHub"}, "message": "Fix pager", "tree": {"sha": "abc"}},
+            "repository": {"description": "", "fullName": "cli/cli", "id": "R_1", "isFork": false,
+            "isPrivate": false, "name": "cli", "owner": {"login": "cli"}, "url": ""},
+            "sha": "77507cd", "url": "https://github.com/cli/cli/commit/77507cd"}]"#;
+        let commits: Vec<CommitSearchResult> = serde_json::from_str(json).unwrap();
+        assert_eq!(commits[0].repository.name_with_owner, "cli/cli");
+        assert_eq!(commits[0].commit.author.name, "Mona");
+        assert_eq!(commits[0].author.as_ref().unwrap().login, "monalisa");
+    }
+
+    #[test]
+    fn test_code_search_decoding() {
+        let json = r#"[{"path": "pkg/cmd/root.go", "repository": {"id": "R_1", "isFork": false,
+            "isPrivate": false, "nameWithOwner": "cli/cli", "url": "https://github.com/cli/cli"},
+            "sha": "abc", "textMatches": [{"fragment": "func NewCmdRoot", "matches": [],
+            "object_type": "FileContent", "property": "content"}],
+            "url": "https://github.com/cli/cli/blob/trunk/pkg/cmd/root.go"}]"#;
+        let files: Vec<CodeSearchResult> = serde_json::from_str(json).unwrap();
+        assert_eq!(files[0].repository.name_with_owner, "cli/cli");
+        assert_eq!(files[0].text_matches[0].fragment, "func NewCmdRoot");
+    }
+
+    #[test]
+    fn test_state_filter_args() {
+        let cmd = SearchCommands::new(Arc::new(GhExecutor::default()))
+            .issues("crash")
+            .state(SearchState::Closed);
+        assert_eq!(
+            cmd.build_args(),
+            vec!["search", "issues", "crash", "--state", "closed"]
+        );
+    }
+}
@@ -0,0 +1,317 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::api::paginated;
+use crate::commands::key_input::KeyInput;
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// SSH key commands namespace
+#[derive(Clone)]
+pub struct SshKeyCommands {
+    executor: Arc<GhExecutor>,
+}
+
+impl SshKeyCommands {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// List SSH keys of the authenticated user
+    pub fn list(&self) -> SshKeyListCommand {
+        SshKeyListCommand::new(self.executor.clone())
+    }
+
+    /// Add an SSH key
+    /// # Example
+    /// ```no_run
+    /// # use gh_cli_rs::{GhClient, SshKeyType};
+    /// let client = GhClient::new();
+    /// client
+    ///     .ssh_key()
+    ///     .add()
+    ///     .key("ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI... deploy@ci")
+    ///     .title("ci signing key")
+    ///     .key_type(SshKeyType::Signing)
+    ///     .execute();
+    /// ```
+    pub fn add(&self) -> SshKeyAddCommand {
+        SshKeyAddCommand::new(self.executor.clone())
+    }
+
+    /// Delete an authentication SSH key by ID
+    pub fn delete(&self, id: u64) -> SshKeyDeleteCommand {
+        SshKeyDeleteCommand::new(self.executor.clone(), id, SshKeyType::Authentication)
+    }
+
+    /// Delete an SSH signing key by ID (`gh ssh-key delete` only removes authentication keys)
+    pub fn delete_signing(&self, id: u64) -> SshKeyDeleteCommand {
+        SshKeyDeleteCommand::new(self.executor.clone(), id, SshKeyType::Signing)
+    }
+}
+
+/// Purpose of an SSH key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SshKeyType {
+    Authentication,
+    Signing,
+}
+
+impl SshKeyType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SshKeyType::Authentication => "authentication",
+            SshKeyType::Signing => "signing",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ApiSshKey {
+    id: u64,
+    key: String,
+    #[serde(default)]
+    title: String,
+    created_at: Timestamp,
+}
+
+/// An SSH key returned by `SshKeyListCommand::fetch`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SshKey {
+    pub id: u64,
+    pub title: String,
+    /// Public key material (e.g. `ssh-ed25519 AAAA...`)
+    pub key: String,
+    pub key_type: SshKeyType,
+    pub created_at: Timestamp,
+}
+
+/// Command for listing SSH keys
+pub struct SshKeyListCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+}
+
+impl SshKeyListCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["ssh-key", "list"]),
+        }
+    }
+
+    /// Execute and get raw output
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Fetch authentication and signing keys from the API (`gh ssh-key list` has no JSON output)
+    pub fn fetch(&self) -> Result<Vec<SshKey>> {
+        let mut keys = Vec::new();
+        for (endpoint, key_type) in [
+            ("user/keys", SshKeyType::Authentication),
+            ("user/ssh_signing_keys", SshKeyType::Signing),
+        ] {
+            let found: Vec<ApiSshKey> = self
+                .executor
+                .execute_json_pages(&paginated(endpoint, &[]))?;
+            keys.extend(found.into_iter().map(|key| SshKey {
+                id: key.id,
+                title: key.title,
+                key: key.key,
+                key_type,
+                created_at: key.created_at,
+            }));
+        }
+        Ok(keys)
+    }
+}
+
+impl GhCommand for SshKeyListCommand {
+    fn build_args(&self) -> Vec<String> {
+        self.cmd.build_args()
+    }
+}
+
+/// Command for adding an SSH key
+///
+/// The key is read from `.key_file()` or, with `.key()`, passed via stdin.
+pub struct SshKeyAddCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    input: KeyInput,
+}
+
+impl SshKeyAddCommand {
+    fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["ssh-key", "add"]),
+            input: KeyInput::default(),
+        }
+    }
+
+    /// Public key material to upload
+    pub fn key(mut self, key: &str) -> Self {
+        self.input.key = Some(key.to_string());
+        self
+    }
+
+    /// Path to a public key file to upload
+    pub fn key_file(mut self, path: &str) -> Self {
+        self.input.key_file = Some(path.to_string());
+        self
+    }
+
+    /// Title of the key
+    pub fn title(mut self, title: &str) -> Self {
+        self.cmd = self.cmd.option("--title", title);
+        self
+    }
+
+    /// Purpose of the key (gh defaults to authentication)
+    pub fn key_type(mut self, key_type: SshKeyType) -> Self {
+        self.cmd = self.cmd.option("--type", key_type.as_str());
+        self
+    }
+
+    /// Execute the add command
+    pub fn execute(&self) -> Result<String> {
+        self.input
+            .execute(&self.executor, "ssh-key add", &self.build_args())
+    }
+}
+
+impl GhCommand for SshKeyAddCommand {
+    fn build_args(&self) -> Vec<String> {
+        let mut args = self.cmd.build_args();
+        args.extend(self.input.arg());
+        args
+    }
+}
+
+/// Command for deleting an SSH key
+///
+/// Signing keys are deleted with `gh api -X DELETE user/ssh_signing_keys/{id}`,
+/// which never prompts.
+pub struct SshKeyDeleteCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    id: u64,
+    key_type: SshKeyType,
+}
+
+impl SshKeyDeleteCommand {
+    fn new(executor: Arc<GhExecutor>, id: u64, key_type: SshKeyType) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::with_subcommands(&["ssh-key", "delete"]).arg(&id.to_string()),
+            id,
+            key_type,
+        }
+    }
+
+    /// Confirm deletion without prompting (required for authentication keys when not interactive)
+    pub fn yes(mut self) -> Self {
+        self.cmd = self.cmd.flag("--yes");
+        self
+    }
+
+    /// Execute the delete command
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+}
+
+impl GhCommand for SshKeyDeleteCommand {
+    fn build_args(&self) -> Vec<String> {
+        match self.key_type {
+            SshKeyType::Authentication => self.cmd.build_args(),
+            SshKeyType::Signing => vec![
+                "api".to_string(),
+                "-X".to_string(),
+                "DELETE".to_string(),
+                format!("user/ssh_signing_keys/{}", self.id),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use std::collections::BTreeMap;
+
+    fn interaction(args: &[&str], stdout: &str) -> Interaction {
+        Interaction {
+            args: args.iter().map(|s| s.to_string()).collect(),
+            env: BTreeMap::new(),
+            stdin: None,
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            exit_code: 0,
+        }
+    }
+
+    #[test]
+    fn test_fetch_reads_every_page_of_both_kinds() {
+        let key = |id: u64| {
+            format!(
+                r#"{{"id": {}, "key": "ssh-ed25519 AAAA{}", "title": "k{}", "created_at": "2024-01-01T00:00:00Z"}}"#,
+                id, id, id
+            )
+        };
+        let executor = GhExecutor::default().with_cassette(Cassette::from_interactions(vec![
+            interaction(
+                &["api", "--paginate", "user/keys?per_page=100"],
+                &format!("[{}]\n[{}]\n", key(1), key(2)),
+            ),
+            interaction(
+                &["api", "--paginate", "user/ssh_signing_keys?per_page=100"],
+                &format!("[{}]\n", key(3)),
+            ),
+        ]));
+
+        let keys = SshKeyListCommand::new(Arc::new(executor)).fetch().unwrap();
+        let summary: Vec<(u64, SshKeyType)> = keys.iter().map(|k| (k.id, k.key_type)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, SshKeyType::Authentication),
+                (2, SshKeyType::Authentication),
+                (3, SshKeyType::Signing),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_args() {
+        let executor = Arc::new(GhExecutor::default());
+        let add = SshKeyAddCommand::new(executor.clone())
+            .key("ssh-ed25519 AAAA")
+            .title("ci")
+            .key_type(SshKeyType::Signing);
+        assert_eq!(
+            add.build_args(),
+            vec!["ssh-key", "add", "--title", "ci", "--type", "signing", "-"]
+        );
+
+        let add = SshKeyAddCommand::new(executor).key_file("id.pub");
+        assert_eq!(add.build_args(), vec!["ssh-key", "add", "id.pub"]);
+    }
+
+    #[test]
+    fn test_delete_args() {
+        let keys = SshKeyCommands::new(Arc::new(GhExecutor::default()));
+        assert_eq!(
+            keys.delete(1).yes().build_args(),
+            vec!["ssh-key", "delete", "1", "--yes"]
+        );
+        assert_eq!(
+            keys.delete_signing(3).yes().build_args(),
+            vec!["api", "-X", "DELETE", "user/ssh_signing_keys/3"]
+        );
+    }
+}
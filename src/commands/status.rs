@@ -0,0 +1,452 @@
+use crate::command::{BaseCommand, CommandBuilder, GhCommand};
+use crate::commands::search::{
+    IssueSearchResult, SearchCommands, SearchIssuesCommand, SearchState,
+};
+use crate::error::Result;
+use crate::executor::GhExecutor;
+use crate::types::Timestamp;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Maximum number of items fetched per status section
+const SECTION_LIMIT: u32 = 50;
+
+/// Page size for the notifications and events APIs (the maximum they allow)
+const PAGE_SIZE: usize = 100;
+
+/// The events API only serves the 300 most recent events
+const EVENT_PAGES: u32 = 3;
+
+/// Why a notification was received
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationReason {
+    Assign,
+    Author,
+    Comment,
+    CiActivity,
+    Invitation,
+    Manual,
+    Mention,
+    ReviewRequested,
+    SecurityAlert,
+    StateChange,
+    Subscribed,
+    TeamMention,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Thread a notification is about
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct NotificationSubject {
+    pub title: String,
+    /// `Issue`, `PullRequest`, `Release`, `Discussion`, ...
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// API URL of the thread
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// Repository a notification belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct NotificationRepository {
+    pub full_name: String,
+}
+
+/// A notification used for the mentions section
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Notification {
+    pub id: String,
+    pub reason: NotificationReason,
+    #[serde(default)]
+    pub unread: bool,
+    pub updated_at: Timestamp,
+    pub subject: NotificationSubject,
+    pub repository: NotificationRepository,
+}
+
+/// Event types reported as repository activity, as in `gh status`
+const ACTIVITY_EVENTS: &[&str] = &[
+    "IssuesEvent",
+    "IssueCommentEvent",
+    "PullRequestEvent",
+    "PullRequestReviewEvent",
+    "PullRequestReviewCommentEvent",
+];
+
+/// User who triggered an event
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EventActor {
+    pub login: String,
+}
+
+/// Repository an event happened in
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EventRepository {
+    /// `owner/name`
+    pub name: String,
+}
+
+/// An event from a repository or user you watch, used for the activity section
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RepositoryEvent {
+    pub id: String,
+    /// `IssuesEvent`, `PullRequestEvent`, ...
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub actor: EventActor,
+    pub repo: EventRepository,
+    pub created_at: Timestamp,
+    /// Event specific details (e.g. the issue or comment)
+    #[serde(default)]
+    pub payload: Option<serde_json::Value>,
+}
+
+/// Sections of the `gh status` dashboard returned by `StatusCommand::fetch`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Status {
+    pub assigned_issues: Vec<IssueSearchResult>,
+    pub assigned_prs: Vec<IssueSearchResult>,
+    pub review_requests: Vec<IssueSearchResult>,
+    /// Notifications for @-mentions of you or your teams
+    pub mentions: Vec<Notification>,
+    /// Issue and pull request events by others in repositories and users you watch
+    pub repository_activity: Vec<RepositoryEvent>,
+}
+
+/// Command for the `gh status` dashboard
+///
+/// `gh status` has no JSON output, so `fetch()` builds the same sections from
+/// `gh search`, the notifications API and your received events, applying the
+/// same filters.
+pub struct StatusCommand {
+    executor: Arc<GhExecutor>,
+    cmd: BaseCommand,
+    org: Option<String>,
+    exclude: Vec<String>,
+}
+
+impl StatusCommand {
+    pub(crate) fn new(executor: Arc<GhExecutor>) -> Self {
+        Self {
+            executor,
+            cmd: BaseCommand::new("status"),
+            org: None,
+            exclude: Vec::new(),
+        }
+    }
+
+    /// Only report activity in repositories of this organization
+    pub fn org(mut self, org: &str) -> Self {
+        self.cmd = self.cmd.option("--org", org);
+        self.org = Some(org.to_string());
+        self
+    }
+
+    /// Leave out a repository (`owner/name`)
+    pub fn exclude(mut self, repo: &str) -> Self {
+        self.exclude.push(repo.to_string());
+        self
+    }
+
+    /// Execute and get the rendered dashboard
+    pub fn execute(&self) -> Result<String> {
+        GhCommand::execute(self, self.executor.as_ref())
+    }
+
+    /// Fetch and parse the dashboard sections
+    pub fn fetch(&self) -> Result<Status> {
+        let search = SearchCommands::new(self.executor.clone());
+        let scoped = |cmd: SearchIssuesCommand| {
+            let mut cmd = cmd.state(SearchState::Open).limit(SECTION_LIMIT);
+            if let Some(org) = &self.org {
+                cmd = cmd.owner(org);
+            }
+            self.exclude
+                .iter()
+                .fold(cmd, |cmd, repo| cmd.exclude_repo(repo))
+        };
+
+        let assigned_issues = scoped(search.issues("").assignee("@me")).fetch()?;
+        let assigned_prs = scoped(search.prs("").assignee("@me")).fetch()?;
+        let review_requests = scoped(search.prs("").review_requested("@me")).fetch()?;
+
+        // Neither API can filter by repository, so these are filtered here
+        let mentions = self.collect_pages(
+            "notifications?all=true&participating=true",
+            None,
+            |n: &Notification| {
+                self.keeps(&n.repository.full_name)
+                    && matches!(
+                        n.reason,
+                        NotificationReason::Mention | NotificationReason::TeamMention
+                    )
+            },
+        )?;
+        let login = self.executor.execute(&[
+            "api".to_string(),
+            "user".to_string(),
+            "--jq".to_string(),
+            ".login".to_string(),
+        ])?;
+        let login = login.trim();
+        let repository_activity = self.collect_pages(
+            &format!("users/{}/received_events", login),
+            Some(EVENT_PAGES),
+            |e: &RepositoryEvent| {
+                self.keeps(&e.repo.name)
+                    && ACTIVITY_EVENTS.contains(&e.kind.as_str())
+                    && e.actor.login != login
+            },
+        )?;
+
+        Ok(Status {
+            assigned_issues,
+            assigned_prs,
+            review_requests,
+            mentions,
+            repository_activity,
+        })
+    }
+
+    /// Page through `path` until `SECTION_LIMIT` items matching `keep` are
+    /// collected, the pages run out or `max_pages` have been read
+    fn collect_pages<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        max_pages: Option<u32>,
+        keep: impl Fn(&T) -> bool,
+    ) -> Result<Vec<T>> {
+        let limit = SECTION_LIMIT as usize;
+        let separator = if path.contains('?') { '&' } else { '?' };
+        let mut items = Vec::new();
+        let mut page = 1;
+        while items.len() < limit && max_pages.map_or(true, |max| page <= max) {
+            let batch: Vec<T> = self.executor.execute_json(&[
+                "api".to_string(),
+                format!("{}{}per_page={}&page={}", path, separator, PAGE_SIZE, page),
+            ])?;
+            let last = batch.len() < PAGE_SIZE;
+            items.extend(batch.into_iter().filter(|item| keep(item)));
+            if last {
+                break;
+            }
+            page += 1;
+        }
+        items.truncate(limit);
+        Ok(items)
+    }
+
+    /// Check that a repository is not excluded and, with `.org()`, belongs to the organization
+    fn keeps(&self, name: &str) -> bool {
+        let in_org = self.org.as_ref().map_or(true, |org| {
+            name.split('/')
+                .next()
+                .map_or(false, |owner| owner.eq_ignore_ascii_case(org))
+        });
+        in_org && !self.exclude.iter().any(|ex| ex.eq_ignore_ascii_case(name))
+    }
+}
+
+impl GhCommand for StatusCommand {
+    fn build_args(&self) -> Vec<String> {
+        let mut args = self.cmd.build_args();
+        if !self.exclude.is_empty() {
+            args.push("--exclude".to_string());
+            args.push(self.exclude.join(","));
+        }
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{Cassette, Interaction};
+    use crate::commands::search::ISSUE_SEARCH_FIELDS;
+    use std::collections::BTreeMap;
+
+    fn interaction(args: &[&str], stdout: String) -> Interaction {
+        Interaction {
+            args: args.iter().map(|s| s.to_string()).collect(),
+            env: BTreeMap::new(),
+            stdin: None,
+            stdout,
+            stderr: String::new(),
+            exit_code: 0,
+        }
+    }
+
+    fn search(kind: &str, filter: &str) -> Interaction {
+        let mut fields = ISSUE_SEARCH_FIELDS.join(",");
+        if kind == "prs" {
+            fields.push_str(",isDraft");
+        }
+        let args = [
+            "search",
+            kind,
+            filter,
+            "@me",
+            "--state",
+            "open",
+            "--limit",
+            "50",
+            "--owner",
+            "octo",
+            "--json",
+            &fields,
+            "--",
+            "-repo:octo/noisy",
+        ];
+        interaction(&args, "[]".to_string())
+    }
+
+    fn notifications(ids: std::ops::Range<u32>, reason: &str, repo: &str) -> Vec<String> {
+        ids.map(|id| {
+            format!(
+                r#"{{"id": "{}", "reason": "{}", "updated_at": "2024-05-01T10:00:00Z",
+                    "subject": {{"title": "Thread", "type": "Issue"}},
+                    "repository": {{"full_name": "{}"}}}}"#,
+                id, reason, repo
+            )
+        })
+        .collect()
+    }
+
+    fn events(ids: std::ops::Range<u32>, kind: &str, actor: &str) -> Vec<String> {
+        ids.map(|id| {
+            format!(
+                r#"{{"id": "{}", "type": "{}", "actor": {{"login": "{}"}},
+                    "repo": {{"name": "octo/app"}}, "created_at": "2024-05-01T10:00:00Z"}}"#,
+                id, kind, actor
+            )
+        })
+        .collect()
+    }
+
+    fn page(items: Vec<Vec<String>>) -> String {
+        format!("[{}]", items.concat().join(","))
+    }
+
+    #[test]
+    fn test_status_filters() {
+        let executor = Arc::new(GhExecutor::default());
+        let status = StatusCommand::new(executor)
+            .org("octo")
+            .exclude("octo/noisy")
+            .exclude("octo/legacy");
+        assert_eq!(
+            status.build_args(),
+            vec![
+                "status",
+                "--org",
+                "octo",
+                "--exclude",
+                "octo/noisy,octo/legacy"
+            ]
+        );
+
+        assert!(status.keeps("octo/app"));
+        assert!(status.keeps("OCTO/app"));
+        assert!(!status.keeps("Octo/Noisy"));
+        assert!(!status.keeps("other/app"));
+    }
+
+    #[test]
+    fn test_notification_decoding() {
+        let json = r#"{"id": "1", "reason": "team_mention", "unread": true,
+            "updated_at": "2024-05-01T10:00:00Z",
+            "subject": {"title": "Flaky test", "type": "Issue", "url": null},
+            "repository": {"full_name": "octo/app"}}"#;
+        let notification: Notification = serde_json::from_str(json).unwrap();
+        assert_eq!(notification.reason, NotificationReason::TeamMention);
+        assert_eq!(notification.subject.kind, "Issue");
+    }
+
+    #[test]
+    fn test_event_decoding() {
+        let json = r#"{"id": "26", "type": "PullRequestEvent",
+            "actor": {"id": 1, "login": "hubot", "display_login": "hubot"},
+            "repo": {"id": 2, "name": "octo/app", "url": "https://api.github.com/repos/octo/app"},
+            "payload": {"action": "opened", "number": 7},
+            "public": true, "created_at": "2024-05-01T10:00:00Z"}"#;
+        let event: RepositoryEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(event.kind, "PullRequestEvent");
+        assert_eq!(event.actor.login, "hubot");
+        assert_eq!(event.repo.name, "octo/app");
+        assert_eq!(event.payload.unwrap()["number"], 7);
+
+        let json = r#"{"id": "27", "type": "WatchEvent", "actor": {"login": "hubot"},
+            "repo": {"name": "octo/app"}, "created_at": "2024-05-01T10:00:00Z"}"#;
+        let event: RepositoryEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(event.payload, None);
+    }
+
+    #[test]
+    fn test_fetch_pages_until_sections_are_full() {
+        let executor = GhExecutor::default().with_cassette(Cassette::from_interactions(vec![
+            search("issues", "--assignee"),
+            search("prs", "--assignee"),
+            search("prs", "--review-requested"),
+            interaction(
+                &[
+                    "api",
+                    "notifications?all=true&participating=true&per_page=100&page=1",
+                ],
+                page(vec![
+                    notifications(0..30, "mention", "octo/app"),
+                    notifications(30..60, "mention", "other/app"),
+                    notifications(60..100, "subscribed", "octo/app"),
+                ]),
+            ),
+            interaction(
+                &[
+                    "api",
+                    "notifications?all=true&participating=true&per_page=100&page=2",
+                ],
+                page(vec![
+                    notifications(100..130, "team_mention", "octo/app"),
+                    notifications(130..140, "mention", "octo/noisy"),
+                ]),
+            ),
+            interaction(&["api", "user", "--jq", ".login"], "monalisa\n".to_string()),
+            interaction(
+                &["api", "users/monalisa/received_events?per_page=100&page=1"],
+                page(vec![
+                    events(0..10, "IssuesEvent", "hubot"),
+                    events(10..50, "IssuesEvent", "monalisa"),
+                    events(50..100, "WatchEvent", "hubot"),
+                ]),
+            ),
+            interaction(
+                &["api", "users/monalisa/received_events?per_page=100&page=2"],
+                page(vec![events(100..200, "WatchEvent", "hubot")]),
+            ),
+            interaction(
+                &["api", "users/monalisa/received_events?per_page=100&page=3"],
+                page(vec![
+                    events(200..210, "PullRequestEvent", "hubot"),
+                    events(210..300, "PushEvent", "hubot"),
+                ]),
+            ),
+        ]));
+
+        let status = StatusCommand::new(Arc::new(executor))
+            .org("octo")
+            .exclude("octo/noisy")
+            .fetch()
+            .unwrap();
+
+        assert_eq!(status.mentions.len(), SECTION_LIMIT as usize);
+        assert_eq!(status.mentions[0].id, "0");
+        assert_eq!(status.mentions[29].id, "29");
+        assert_eq!(status.mentions[30].id, "100");
+        assert_eq!(status.mentions[49].reason, NotificationReason::TeamMention);
+        // Page 4 of the events is never requested
+        assert_eq!(status.repository_activity.len(), 20);
+        assert_eq!(status.repository_activity[10].kind, "PullRequestEvent");
+    }
+}
@@ -1,4 +1,5 @@
 use std::process::Output;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type for gh-cli-rs operations
@@ -22,6 +23,22 @@ pub enum GhError {
     #[error("UTF-8 conversion error: {0}")]
     Utf8Error(#[from] std::string::FromUtf8Error),
 
+    #[error("JSON parse error: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[cfg(feature = "yaml")]
+    #[error("YAML parse error: {0}")]
+    YamlError(#[from] serde_yaml::Error),
+
+    #[error("Command timed out after {0:?}")]
+    Timeout(Duration),
+
+    #[error("{0} require the `{1}` feature")]
+    FeatureDisabled(&'static str, &'static str),
+
+    #[error("Unexpected command in replay: gh {0}")]
+    UnexpectedCommand(String),
+
     #[error("Invalid command: {0}")]
     InvalidCommand(String),
 }
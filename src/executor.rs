@@ -1,31 +1,85 @@
+use crate::cassette::{Cassette, CassetteMode, Interaction};
 use crate::error::{GhError, Result};
-use std::process::{Command, Stdio};
+use crate::limiter::RateLimiter;
+use crate::retry::RetryPolicy;
+use serde::de::DeserializeOwned;
+use std::io::{ErrorKind, Read, Write};
+use std::process::{Child, Command, Output, Stdio};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
 /// Executor for GitHub CLI commands
 #[derive(Debug, Clone)]
 pub struct GhExecutor {
     /// Path to the gh binary (defaults to "gh")
     pub gh_path: String,
+    /// Retry policy for transient failures (defaults to no retries)
+    pub retry_policy: RetryPolicy,
+    /// Limiter bounding concurrent and per-second gh processes, shared between clones
+    pub limiter: Option<Arc<RateLimiter>>,
+    /// Extra environment variables passed to gh (e.g. GH_TOKEN, GH_HOST)
+    pub env: Vec<(String, String)>,
+    /// Cassette for recording or replaying invocations
+    pub cassette: Option<Arc<Cassette>>,
+    /// Time after which a gh process is killed (defaults to no limit)
+    pub timeout: Option<Duration>,
 }
 
 impl Default for GhExecutor {
     fn default() -> Self {
-        Self {
-            gh_path: "gh".to_string(),
-        }
+        Self::new("gh".to_string())
     }
 }
 
 impl GhExecutor {
     /// Create a new executor with a custom gh binary path
     pub fn new(gh_path: String) -> Self {
-        Self { gh_path }
+        Self {
+            gh_path,
+            retry_policy: RetryPolicy::none(),
+            limiter: None,
+            env: Vec::new(),
+            cassette: None,
+            timeout: None,
+        }
+    }
+
+    /// Set the retry policy for transient failures
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Set the limiter for concurrent and per-second gh processes
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = Some(Arc::new(limiter));
+        self
+    }
+
+    /// Set an environment variable for gh processes
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Record invocations to, or replay them from, a cassette
+    pub fn with_cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(Arc::new(cassette));
+        self
+    }
+
+    /// Kill gh processes that run longer than `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
     }
 
     /// Check if gh CLI is installed and accessible
     pub fn check_installation(&self) -> Result<String> {
         let output = Command::new(&self.gh_path)
             .arg("--version")
+            .envs(self.env.iter().map(|(k, v)| (k, v)))
             .output()
             .map_err(|_| GhError::GhNotFound)?;
 
@@ -37,21 +91,147 @@ impl GhExecutor {
     }
 
     /// Execute a gh command with the given arguments
+    ///
+    /// Transient failures are retried according to the retry policy.
     pub fn execute(&self, args: &[String]) -> Result<String> {
-        let output = Command::new(&self.gh_path)
+        self.run(args, None)
+    }
+
+    /// Execute a gh command, writing `input` to its stdin
+    pub fn execute_with_input(&self, args: &[String], input: &str) -> Result<String> {
+        self.run(args, Some(input))
+    }
+
+    /// Execute a gh command and deserialize its JSON output
+    pub fn execute_json<T: DeserializeOwned>(&self, args: &[String]) -> Result<T> {
+        let output = self.execute(args)?;
+        Ok(serde_json::from_str(&output)?)
+    }
+
+    /// Execute a `gh api --paginate` command and collect every page
+    ///
+    /// gh prints each page as its own JSON array, one after the other, so
+    /// the output is decoded as a stream of arrays rather than one document.
+    pub fn execute_json_pages<T: DeserializeOwned>(&self, args: &[String]) -> Result<Vec<T>> {
+        let output = self.execute(args)?;
+        let mut items = Vec::new();
+        for page in serde_json::Deserializer::from_str(&output).into_iter::<Vec<T>>() {
+            items.extend(page?);
+        }
+        Ok(items)
+    }
+
+    fn run(&self, args: &[String], stdin: Option<&str>) -> Result<String> {
+        if !self.retry_policy.applies_to(args) {
+            return self.execute_once(args, stdin);
+        }
+
+        let mut attempt = 1;
+        loop {
+            match self.execute_once(args, stdin) {
+                Err(e)
+                    if attempt < self.retry_policy.attempts()
+                        && self.retry_policy.should_retry(&e) =>
+                {
+                    thread::sleep(self.retry_policy.backoff(attempt));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Execute a gh command once, without retrying
+    fn execute_once(&self, args: &[String], stdin: Option<&str>) -> Result<String> {
+        if let Some(cassette) = &self.cassette {
+            if cassette.mode() == CassetteMode::Replay {
+                return cassette.find(args, stdin)?.to_result();
+            }
+        }
+
+        let _permit = self.limiter.as_ref().map(|limiter| limiter.acquire());
+        let mut child = Command::new(&self.gh_path)
             .args(args)
+            .envs(self.env.iter().map(|(k, v)| (k, v)))
+            .stdin(if stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .output()?;
+            .spawn()?;
 
-        if output.status.success() {
-            Ok(String::from_utf8(output.stdout)?)
-        } else {
-            Err(GhError::from_output(output))
+        // Write stdin from another thread so that gh can't block on a full
+        // stdout pipe while we are still writing its input
+        let writer = match (stdin, child.stdin.take()) {
+            (Some(input), Some(mut pipe)) => {
+                let input = input.to_string();
+                Some(thread::spawn(move || pipe.write_all(input.as_bytes())))
+            }
+            _ => None,
+        };
+        let output = match self.timeout {
+            Some(timeout) => wait_with_timeout(child, timeout)?,
+            None => child.wait_with_output()?,
+        };
+        let written = writer.map_or(Ok(()), |writer| writer.join().unwrap_or(Ok(())));
+
+        if let Some(cassette) = &self.cassette {
+            cassette.push(Interaction::from_output(args, &self.env, stdin, &output))?;
+        }
+
+        if !output.status.success() {
+            return Err(GhError::from_output(output));
+        }
+        match written {
+            // gh exited without reading all of its input
+            Err(e) if e.kind() != ErrorKind::BrokenPipe => Err(e.into()),
+            _ => Ok(String::from_utf8(output.stdout)?),
         }
     }
 }
 
+/// Wait for `child` like `wait_with_output`, killing it once `timeout` has passed
+fn wait_with_timeout(mut child: Child, timeout: Duration) -> Result<Output> {
+    fn drain(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<Vec<u8>> {
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut buf);
+            }
+            buf
+        })
+    }
+
+    // Drain both pipes while polling so that gh never blocks on a full pipe
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            // The readers are left behind: processes spawned by gh may
+            // still hold the pipes open
+            return Err(GhError::Timeout(timeout));
+        }
+        thread::sleep(POLL_INTERVAL.min(deadline - Instant::now()));
+    };
+
+    Ok(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+/// How often `wait_with_timeout` checks whether gh has exited
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,5 +243,47 @@ mod tests {
 
         let custom_executor = GhExecutor::new("/custom/path/gh".to_string());
         assert_eq!(custom_executor.gh_path, "/custom/path/gh");
+        assert_eq!(custom_executor.retry_policy, RetryPolicy::none());
+    }
+
+    #[test]
+    fn test_large_input_and_output_do_not_deadlock() {
+        // `cat` echoes its input, filling the stdout pipe while stdin is written
+        let input = "x".repeat(4 * 1024 * 1024);
+        let executor = GhExecutor::new("cat".to_string());
+        assert_eq!(executor.execute_with_input(&[], &input).unwrap(), input);
+    }
+
+    #[test]
+    fn test_unread_input_is_not_an_error() {
+        let input = "x".repeat(4 * 1024 * 1024);
+        let executor = GhExecutor::new("true".to_string());
+        assert_eq!(executor.execute_with_input(&[], &input).unwrap(), "");
+    }
+
+    #[test]
+    fn test_json_pages_are_concatenated() {
+        // `echo` prints its arguments, standing in for `gh api --paginate`
+        let executor = GhExecutor::new("echo".to_string());
+        let pages: Vec<u32> = executor
+            .execute_json_pages(&["[1, 2][3]\n[]".to_string()])
+            .unwrap();
+        assert_eq!(pages, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_timeout_kills_the_process() {
+        let executor =
+            GhExecutor::new("sleep".to_string()).with_timeout(Duration::from_millis(100));
+        let started = Instant::now();
+        match executor.execute(&["5".to_string()]) {
+            Err(GhError::Timeout(timeout)) => assert_eq!(timeout, Duration::from_millis(100)),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        // Processes finishing in time are unaffected
+        let executor = GhExecutor::new("echo".to_string()).with_timeout(Duration::from_secs(5));
+        assert_eq!(executor.execute(&["done".to_string()]).unwrap(), "done\n");
     }
 }
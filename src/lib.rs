@@ -1,15 +1,28 @@
+mod cassette;
 mod client;
 mod command;
 mod commands;
 mod error;
 mod executor;
+mod limiter;
+mod retry;
+mod selector;
+mod types;
+
+#[cfg(feature = "testing")]
+pub mod testing;
 
 // Public API exports
+pub use cassette::{Cassette, CassetteMode, Interaction, REDACTED};
 pub use client::{GhClient, GhClientBuilder};
 pub use command::{BaseCommand, CommandBuilder, GhCommand};
 pub use commands::*;
 pub use error::{GhError, Result};
 pub use executor::GhExecutor;
+pub use limiter::{Permit, RateLimiter};
+pub use retry::{ErrorClass, RetryPolicy};
+pub use selector::{IssueSelector, PrSelector, ResourceUrl};
+pub use types::{Actor, Comment, Label, Timestamp};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,172 @@
+use std::sync::{Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Longest interval between requests accepted by `requests_per_second`
+const MAX_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Client-side limiter shared by all clones of a `GhClient`
+///
+/// Bounds the number of concurrently running `gh` processes and the rate
+/// at which new ones are spawned.
+///
+/// # Example
+/// ```
+/// # use gh_cli_rs::{GhClient, RateLimiter};
+/// let client = GhClient::builder()
+///     .rate_limiter(RateLimiter::new().max_concurrent(4).requests_per_second(10.0))
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    max_concurrent: Option<usize>,
+    interval: Option<Duration>,
+    running: Mutex<usize>,
+    released: Condvar,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RateLimiter {
+    /// Create a limiter without any bounds
+    pub fn new() -> Self {
+        Self {
+            max_concurrent: None,
+            interval: None,
+            running: Mutex::new(0),
+            released: Condvar::new(),
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// Limit the number of concurrently running gh processes
+    pub fn max_concurrent(mut self, max: usize) -> Self {
+        self.max_concurrent = Some(max.max(1));
+        self
+    }
+
+    /// Limit the number of gh processes started per second
+    ///
+    /// Rates below one request per day are treated as one per day.
+    pub fn requests_per_second(mut self, rps: f64) -> Self {
+        self.interval = if rps > 0.0 {
+            let secs = (1.0 / rps).min(MAX_INTERVAL.as_secs_f64());
+            Some(Duration::from_secs_f64(secs))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Block until a new gh process may be started
+    ///
+    /// The returned permit releases the concurrency slot when dropped.
+    pub fn acquire(&self) -> Permit<'_> {
+        if let Some(max) = self.max_concurrent {
+            let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+            while *running >= max {
+                running = self
+                    .released
+                    .wait(running)
+                    .unwrap_or_else(|e| e.into_inner());
+            }
+            *running += 1;
+        }
+
+        if let Some(interval) = self.interval {
+            let wait = {
+                let mut next_slot = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+                let now = Instant::now();
+                let slot = next_slot.map_or(now, |slot| slot.max(now));
+                *next_slot = Some(slot + interval);
+                slot - now
+            };
+            if !wait.is_zero() {
+                thread::sleep(wait);
+            }
+        }
+
+        Permit { limiter: self }
+    }
+
+    /// Number of gh processes currently holding a permit
+    pub fn running(&self) -> usize {
+        *self.running.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A slot for a running gh process, released on drop
+#[derive(Debug)]
+pub struct Permit<'a> {
+    limiter: &'a RateLimiter,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if self.limiter.max_concurrent.is_some() {
+            let mut running = self
+                .limiter
+                .running
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            *running -= 1;
+            self.limiter.released.notify_one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_concurrency_is_capped() {
+        let limiter = Arc::new(RateLimiter::new().max_concurrent(2));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let limiter = limiter.clone();
+                let peak = peak.clone();
+                thread::spawn(move || {
+                    let _permit = limiter.acquire();
+                    peak.fetch_max(limiter.running(), Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(10));
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+        assert_eq!(limiter.running(), 0);
+    }
+
+    #[test]
+    fn test_tiny_rates_are_clamped() {
+        let limiter = RateLimiter::new().requests_per_second(1e-300);
+        assert_eq!(limiter.interval, Some(MAX_INTERVAL));
+        assert_eq!(
+            RateLimiter::new().requests_per_second(f64::NAN).interval,
+            None
+        );
+    }
+
+    #[test]
+    fn test_requests_are_spaced() {
+        let limiter = RateLimiter::new().requests_per_second(100.0);
+        let start = Instant::now();
+        for _ in 0..3 {
+            drop(limiter.acquire());
+        }
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+}
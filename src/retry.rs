@@ -0,0 +1,336 @@
+use crate::error::GhError;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Classes of transient failures that a `RetryPolicy` may retry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    /// Primary or secondary API rate limit (HTTP 403/429 with a rate limit message)
+    RateLimited,
+    /// Server-side error (HTTP 500, 502, 503, 504)
+    ServerError,
+    /// Network failure such as a timeout, reset connection or DNS error
+    Network,
+}
+
+impl ErrorClass {
+    /// Classify an error, returning `None` if it is not considered transient
+    pub fn of(error: &GhError) -> Option<Self> {
+        let stderr = match error {
+            GhError::CommandFailed { stderr, .. } => stderr.to_lowercase(),
+            _ => return None,
+        };
+
+        if stderr.contains("rate limit") || stderr.contains("http 429") {
+            Some(ErrorClass::RateLimited)
+        } else if [
+            "http 500",
+            "http 502",
+            "http 503",
+            "http 504",
+            "bad gateway",
+        ]
+        .iter()
+        .any(|s| stderr.contains(s))
+        {
+            Some(ErrorClass::ServerError)
+        } else if [
+            "timeout",
+            "timed out",
+            "connection reset",
+            "connection refused",
+            "no such host",
+            "unexpected eof",
+        ]
+        .iter()
+        .any(|s| stderr.contains(s))
+        {
+            Some(ErrorClass::Network)
+        } else {
+            None
+        }
+    }
+}
+
+/// Retry policy with exponential backoff applied by the executor
+///
+/// By default only read-only commands (`list`, `view`, `status`, ...) are retried,
+/// since retrying a mutating command such as `pr create` may apply it twice.
+/// Use [`RetryPolicy::retry_mutating`] to opt in for all commands.
+///
+/// # Example
+/// ```
+/// # use gh_cli_rs::{GhClient, RetryPolicy};
+/// # use std::time::Duration;
+/// let client = GhClient::builder()
+///     .retry_policy(
+///         RetryPolicy::new()
+///             .max_attempts(5)
+///             .initial_backoff(Duration::from_secs(1)),
+///     )
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: bool,
+    retryable: Vec<ErrorClass>,
+    retry_mutating: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy with 3 attempts, 500ms initial backoff doubling up to 30s,
+    /// jitter enabled, retrying all error classes for read-only commands
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+            retryable: vec![
+                ErrorClass::RateLimited,
+                ErrorClass::ServerError,
+                ErrorClass::Network,
+            ],
+            retry_mutating: false,
+        }
+    }
+
+    /// Create a policy that never retries
+    pub fn none() -> Self {
+        Self::new().max_attempts(1)
+    }
+
+    /// Set the maximum number of attempts, including the first one
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the upper bound for the delay between attempts
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set the factor the delay is multiplied by after each attempt
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Enable or disable random jitter on the delay
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Set which error classes are retried
+    pub fn retry_on(mut self, classes: &[ErrorClass]) -> Self {
+        self.retryable = classes.to_vec();
+        self
+    }
+
+    /// Also retry commands that modify state (create, merge, delete, ...)
+    pub fn retry_mutating(mut self, retry: bool) -> Self {
+        self.retry_mutating = retry;
+        self
+    }
+
+    /// Get the maximum number of attempts
+    pub fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Check whether the policy applies to the given command arguments
+    pub fn applies_to(&self, args: &[String]) -> bool {
+        self.max_attempts > 1 && (self.retry_mutating || is_read_only(args))
+    }
+
+    /// Check whether an error should be retried
+    pub fn should_retry(&self, error: &GhError) -> bool {
+        ErrorClass::of(error).map_or(false, |class| self.retryable.contains(&class))
+    }
+
+    /// Compute the delay before the given retry (1 for the first retry)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(32) as i32;
+        // Clamp in f64 first: large multipliers overflow `Duration`
+        let secs = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        let delay = if secs < self.max_backoff.as_secs_f64() {
+            Duration::from_secs_f64(secs)
+        } else {
+            self.max_backoff
+        };
+
+        if self.jitter {
+            // Equal jitter: keep half of the delay, randomize the other half
+            let half = delay / 2;
+            half + half.mul_f64(random_fraction())
+        } else {
+            delay
+        }
+    }
+}
+
+/// Check whether the arguments describe a command that does not modify state
+pub(crate) fn is_read_only(args: &[String]) -> bool {
+    const READ_ONLY_VERBS: &[&str] = &["list", "view", "status", "diff", "checks"];
+
+    match args.first().map(String::as_str) {
+        Some("search") | Some("status") => true,
+        Some("browse") => args.iter().any(|a| a == "--no-browser"),
+        Some("api") => !args.iter().any(|a| is_mutating_api_flag(a)),
+        Some(_) => args
+            .get(1)
+            .map_or(false, |verb| READ_ONLY_VERBS.contains(&verb.as_str())),
+        None => false,
+    }
+}
+
+/// Check whether a `gh api` argument sets the method, fields or a request body
+///
+/// Values may be attached (`-XPOST`, `-fkey=v`) or given with `=` (`--method=POST`).
+fn is_mutating_api_flag(arg: &str) -> bool {
+    const SHORT: &[&str] = &["-X", "-f", "-F"];
+    const LONG: &[&str] = &["--method", "--field", "--raw-field", "--input"];
+
+    if arg.starts_with("--") {
+        LONG.iter().any(|flag| {
+            arg.strip_prefix(flag)
+                .map_or(false, |rest| rest.is_empty() || rest.starts_with('='))
+        })
+    } else {
+        SHORT.iter().any(|flag| arg.starts_with(flag))
+    }
+}
+
+/// Cheap pseudo-random number in [0, 1) used for jitter
+fn random_fraction() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let mut x = u64::from(nanos) ^ 0x9E37_79B9_7F4A_7C15;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    (x % 1_000_000) as f64 / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn failed(stderr: &str) -> GhError {
+        GhError::CommandFailed {
+            code: 1,
+            stderr: stderr.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_error_classification() {
+        assert_eq!(
+            ErrorClass::of(&failed("You have exceeded a secondary rate limit")),
+            Some(ErrorClass::RateLimited)
+        );
+        assert_eq!(
+            ErrorClass::of(&failed("HTTP 502: Bad Gateway")),
+            Some(ErrorClass::ServerError)
+        );
+        assert_eq!(
+            ErrorClass::of(&failed("dial tcp: i/o timeout")),
+            Some(ErrorClass::Network)
+        );
+        assert_eq!(ErrorClass::of(&failed("HTTP 404: Not Found")), None);
+        assert_eq!(ErrorClass::of(&GhError::GhNotFound), None);
+    }
+
+    #[test]
+    fn test_applies_only_to_read_only_by_default() {
+        let policy = RetryPolicy::new();
+        assert!(policy.applies_to(&args(&["pr", "list"])));
+        assert!(policy.applies_to(&args(&["api", "repos/cli/cli"])));
+        assert!(!policy.applies_to(&args(&["pr", "create", "--title", "x"])));
+        assert!(!policy.applies_to(&args(&["api", "-X", "DELETE", "repos/a/b"])));
+        for mutating in [
+            "-XPOST",
+            "--method=POST",
+            "--field=k=v",
+            "--raw-field=k=v",
+            "-fkey=v",
+            "-Fkey=1",
+            "--input=body.json",
+        ] {
+            assert!(
+                !policy.applies_to(&args(&["api", mutating, "repos/a/b"])),
+                "{} should not be retried",
+                mutating
+            );
+        }
+        assert!(policy.applies_to(&args(&["api", "--paginate", "--jq", ".[]", "user/orgs"])));
+
+        let policy = policy.retry_mutating(true);
+        assert!(policy.applies_to(&args(&["pr", "create"])));
+
+        assert!(!RetryPolicy::none().applies_to(&args(&["pr", "list"])));
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(350))
+            .jitter(false);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_backoff_does_not_overflow() {
+        let policy = RetryPolicy::new()
+            .multiplier(10.0)
+            .max_attempts(40)
+            .jitter(false);
+        assert_eq!(policy.backoff(39), Duration::from_secs(30));
+
+        let policy = policy.multiplier(f64::MAX).max_backoff(Duration::MAX);
+        assert_eq!(policy.backoff(u32::MAX), Duration::MAX);
+    }
+
+    #[test]
+    fn test_backoff_jitter_stays_in_range() {
+        let policy = RetryPolicy::new().initial_backoff(Duration::from_millis(100));
+        let delay = policy.backoff(1);
+        assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_should_retry_respects_classes() {
+        let policy = RetryPolicy::new().retry_on(&[ErrorClass::ServerError]);
+        assert!(policy.should_retry(&failed("HTTP 503")));
+        assert!(!policy.should_retry(&failed("API rate limit exceeded")));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::command::{BaseCommand, CommandBuilder};
+
+/// Repository and number parsed from a GitHub issue or pull request URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceUrl {
+    /// Host name, e.g. "github.com" or a GitHub Enterprise host
+    pub host: String,
+    pub owner: String,
+    pub repo: String,
+    pub number: u32,
+}
+
+impl ResourceUrl {
+    /// Parse `https://HOST/OWNER/REPO/<kind>/NUMBER`, where kind is "pull" or "issues"
+    fn parse(url: &str, kinds: &[&str]) -> Option<Self> {
+        let rest = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))?;
+        let rest = rest.split(['?', '#']).next().unwrap_or(rest);
+        let parts: Vec<&str> = rest.trim_end_matches('/').split('/').collect();
+
+        match parts.as_slice() {
+            [host, owner, repo, kind, number, ..] if kinds.contains(kind) => Some(Self {
+                host: host.to_string(),
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+                number: number.parse().ok()?,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Repository in the form accepted by `--repo`
+    pub fn repo_arg(&self) -> String {
+        if self.host == "github.com" {
+            format!("{}/{}", self.owner, self.repo)
+        } else {
+            format!("{}/{}/{}", self.host, self.owner, self.repo)
+        }
+    }
+
+    fn apply(&self, cmd: BaseCommand) -> BaseCommand {
+        cmd.arg(&self.number.to_string())
+            .option("--repo", &self.repo_arg())
+    }
+}
+
+/// Parse "123" or "#123" as a number
+fn parse_number(s: &str) -> Option<u32> {
+    s.strip_prefix('#').unwrap_or(s).parse().ok()
+}
+
+/// Identifies a pull request by number, URL or head branch
+///
+/// Strings are parsed: numbers (optionally prefixed with `#`) and pull request
+/// URLs are recognized, anything else is treated as a branch name. URLs add
+/// `--repo` automatically so the command works outside a checkout.
+///
+/// # Example
+/// ```no_run
+/// # use gh_cli_rs::GhClient;
+/// let client = GhClient::new();
+/// client.pr().view(123).execute();
+/// client.pr().view("https://github.com/cli/cli/pull/123").execute();
+/// client.pr().view("feature-branch").execute();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrSelector {
+    Number(u32),
+    Url(ResourceUrl),
+    Branch(String),
+}
+
+impl PrSelector {
+    /// Append the selector arguments to a command
+    pub(crate) fn apply(&self, cmd: BaseCommand) -> BaseCommand {
+        match self {
+            PrSelector::Number(number) => cmd.arg(&number.to_string()),
+            PrSelector::Url(url) => url.apply(cmd),
+            PrSelector::Branch(branch) => cmd.arg(branch),
+        }
+    }
+}
+
+impl From<u32> for PrSelector {
+    fn from(number: u32) -> Self {
+        PrSelector::Number(number)
+    }
+}
+
+impl From<&str> for PrSelector {
+    fn from(s: &str) -> Self {
+        if let Some(number) = parse_number(s) {
+            PrSelector::Number(number)
+        } else if let Some(url) = ResourceUrl::parse(s, &["pull"]) {
+            PrSelector::Url(url)
+        } else {
+            PrSelector::Branch(s.to_string())
+        }
+    }
+}
+
+impl From<String> for PrSelector {
+    fn from(s: String) -> Self {
+        PrSelector::from(s.as_str())
+    }
+}
+
+impl From<&String> for PrSelector {
+    fn from(s: &String) -> Self {
+        PrSelector::from(s.as_str())
+    }
+}
+
+/// Identifies an issue by number or URL
+///
+/// Strings are parsed: numbers (optionally prefixed with `#`) and issue URLs
+/// are recognized, anything else is passed to gh unchanged. URLs add
+/// `--repo` automatically so the command works outside a checkout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueSelector {
+    Number(u32),
+    Url(ResourceUrl),
+    Other(String),
+}
+
+impl IssueSelector {
+    /// Append the selector arguments to a command
+    pub(crate) fn apply(&self, cmd: BaseCommand) -> BaseCommand {
+        match self {
+            IssueSelector::Number(number) => cmd.arg(&number.to_string()),
+            IssueSelector::Url(url) => url.apply(cmd),
+            IssueSelector::Other(other) => cmd.arg(other),
+        }
+    }
+}
+
+impl From<u32> for IssueSelector {
+    fn from(number: u32) -> Self {
+        IssueSelector::Number(number)
+    }
+}
+
+impl From<&str> for IssueSelector {
+    fn from(s: &str) -> Self {
+        if let Some(number) = parse_number(s) {
+            IssueSelector::Number(number)
+        } else if let Some(url) = ResourceUrl::parse(s, &["issues"]) {
+            IssueSelector::Url(url)
+        } else {
+            IssueSelector::Other(s.to_string())
+        }
+    }
+}
+
+impl From<String> for IssueSelector {
+    fn from(s: String) -> Self {
+        IssueSelector::from(s.as_str())
+    }
+}
+
+impl From<&String> for IssueSelector {
+    fn from(s: &String) -> Self {
+        IssueSelector::from(s.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::GhCommand;
+
+    fn args(selector: PrSelector) -> Vec<String> {
+        selector
+            .apply(BaseCommand::with_subcommands(&["pr", "view"]))
+            .build_args()
+    }
+
+    #[test]
+    fn test_pr_selector_parsing() {
+        assert_eq!(PrSelector::from(12), PrSelector::Number(12));
+        assert_eq!(PrSelector::from("#12"), PrSelector::Number(12));
+        assert_eq!(
+            PrSelector::from("feature/login"),
+            PrSelector::Branch("feature/login".to_string())
+        );
+        assert_eq!(
+            PrSelector::from("https://github.com/cli/cli/pull/42/files?w=1"),
+            PrSelector::Url(ResourceUrl {
+                host: "github.com".to_string(),
+                owner: "cli".to_string(),
+                repo: "cli".to_string(),
+                number: 42,
+            })
+        );
+    }
+
+    #[test]
+    fn test_url_selector_applies_repo() {
+        assert_eq!(
+            args("https://github.com/cli/cli/pull/42".into()),
+            vec!["pr", "view", "42", "--repo", "cli/cli"]
+        );
+        assert_eq!(
+            args("https://ghe.example.com/org/app/pull/7".into()),
+            vec!["pr", "view", "7", "--repo", "ghe.example.com/org/app"]
+        );
+    }
+
+    #[test]
+    fn test_issue_selector_parsing() {
+        assert_eq!(IssueSelector::from("42"), IssueSelector::Number(42));
+        assert!(matches!(
+            IssueSelector::from("https://github.com/cli/cli/issues/9"),
+            IssueSelector::Url(ResourceUrl { number: 9, .. })
+        ));
+        // A pull request URL is not an issue URL
+        assert!(matches!(
+            IssueSelector::from("https://github.com/cli/cli/pull/9"),
+            IssueSelector::Other(_)
+        ));
+    }
+}
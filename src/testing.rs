@@ -0,0 +1,186 @@
+//! Scenario files for the `gh-fake` binary
+//!
+//! `gh-fake` is a stand-in for gh that answers from a scenario file named by
+//! the `GH_FAKE_SCENARIO` environment variable, so tests exercise the real
+//! process-spawning path of `GhExecutor` without gh or network access.
+//!
+//! # Example
+//! ```no_run
+//! # use gh_cli_rs::GhClient;
+//! # use gh_cli_rs::testing::{Rule, Scenario, SCENARIO_ENV};
+//! let scenario = Scenario::new()
+//!     .rule(Rule::new(&["pr", "list", "**"]).stdout("#1 Fix bug\n"))
+//!     .rule(Rule::new(&["pr", "merge", "*"]).stderr("HTTP 502").exit_code(1));
+//! scenario.save("target/scenario.json").unwrap();
+//!
+//! // Integration tests can use env!("CARGO_BIN_EXE_gh-fake") for the path
+//! let client = GhClient::builder()
+//!     .gh_path("target/debug/gh-fake")
+//!     .env(SCENARIO_ENV, "target/scenario.json")
+//!     .build();
+//! ```
+
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Environment variable holding the path of the scenario file
+pub const SCENARIO_ENV: &str = "GH_FAKE_SCENARIO";
+
+/// Exit code used by `gh-fake` when no rule matches
+pub const NO_MATCH_EXIT_CODE: i32 = 127;
+
+/// A response for invocations whose arguments match a pattern
+///
+/// Pattern elements match one argument exactly, except `*` which matches any
+/// single argument and `**` which matches all remaining arguments.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rule {
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub stdout: String,
+    #[serde(default)]
+    pub stderr: String,
+    #[serde(default)]
+    pub exit_code: i32,
+    /// Delay before responding, in milliseconds
+    #[serde(default)]
+    pub delay_ms: u64,
+}
+
+impl Rule {
+    /// Create a rule for the given argument pattern that succeeds with no output
+    pub fn new(pattern: &[&str]) -> Self {
+        Self {
+            args: pattern.iter().map(|s| s.to_string()).collect(),
+            stdout: String::new(),
+            stderr: String::new(),
+            exit_code: 0,
+            delay_ms: 0,
+        }
+    }
+
+    /// Set the output written to stdout
+    pub fn stdout(mut self, stdout: &str) -> Self {
+        self.stdout = stdout.to_string();
+        self
+    }
+
+    /// Set the output written to stderr
+    pub fn stderr(mut self, stderr: &str) -> Self {
+        self.stderr = stderr.to_string();
+        self
+    }
+
+    /// Set the exit code
+    pub fn exit_code(mut self, code: i32) -> Self {
+        self.exit_code = code;
+        self
+    }
+
+    /// Delay the response
+    pub fn delay_ms(mut self, delay_ms: u64) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    /// Check whether the rule's pattern matches the arguments
+    pub fn matches(&self, args: &[String]) -> bool {
+        let mut args = args.iter();
+        for pattern in &self.args {
+            match pattern.as_str() {
+                "**" => return true,
+                "*" => {
+                    if args.next().is_none() {
+                        return false;
+                    }
+                }
+                expected => {
+                    if args.next().map(String::as_str) != Some(expected) {
+                        return false;
+                    }
+                }
+            }
+        }
+        args.next().is_none()
+    }
+}
+
+/// Ordered list of rules; the first matching rule answers
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Scenario {
+    pub rules: Vec<Rule>,
+}
+
+impl Scenario {
+    /// Create an empty scenario
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a rule
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Find the first rule matching the arguments
+    pub fn find(&self, args: &[String]) -> Option<&Rule> {
+        self.rules.iter().find(|rule| rule.matches(args))
+    }
+
+    /// Load a scenario file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Write the scenario file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_rule_matching() {
+        let exact = Rule::new(&["pr", "view", "1"]);
+        assert!(exact.matches(&args(&["pr", "view", "1"])));
+        assert!(!exact.matches(&args(&["pr", "view", "1", "--web"])));
+
+        let any = Rule::new(&["pr", "view", "*"]);
+        assert!(any.matches(&args(&["pr", "view", "2"])));
+        assert!(!any.matches(&args(&["pr", "view"])));
+
+        let rest = Rule::new(&["pr", "**"]);
+        assert!(rest.matches(&args(&["pr"])));
+        assert!(rest.matches(&args(&["pr", "list", "--limit", "5"])));
+        assert!(!rest.matches(&args(&["issue", "list"])));
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let scenario = Scenario::new()
+            .rule(Rule::new(&["pr", "view", "1"]).stdout("first"))
+            .rule(Rule::new(&["pr", "**"]).stdout("fallback"));
+
+        assert_eq!(
+            scenario.find(&args(&["pr", "view", "1"])).unwrap().stdout,
+            "first"
+        );
+        assert_eq!(
+            scenario.find(&args(&["pr", "list"])).unwrap().stdout,
+            "fallback"
+        );
+        assert!(scenario.find(&args(&["repo", "list"])).is_none());
+    }
+}
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// RFC 3339 timestamp as returned by gh (e.g. "2024-05-01T12:30:00Z")
+///
+/// Kept as the original string so no date library is required. It is not
+/// `Ord`, since strings with different UTC offsets don't sort by instant; use
+/// [`Timestamp::unix_seconds`] or [`Timestamp::to_system_time`] to compare.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Timestamp(String);
+
+impl Timestamp {
+    /// Wrap an RFC 3339 string
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Get the original string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Seconds since the Unix epoch, or `None` if the string is not valid RFC 3339
+    pub fn unix_seconds(&self) -> Option<i64> {
+        parse_rfc3339(&self.0)
+    }
+
+    /// Convert to a `SystemTime`, or `None` if the string is not valid RFC 3339
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let seconds = self.unix_seconds()?;
+        if seconds >= 0 {
+            Some(UNIX_EPOCH + Duration::from_secs(seconds as u64))
+        } else {
+            Some(UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs()))
+        }
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A GitHub user or bot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Actor {
+    pub login: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub is_bot: bool,
+}
+
+/// A label attached to an issue or pull request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Label {
+    pub name: String,
+    #[serde(default)]
+    pub color: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// A comment on an issue or pull request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    pub author: Actor,
+    pub body: String,
+    pub created_at: Timestamp,
+    #[serde(default)]
+    pub author_association: String,
+    #[serde(default)]
+    pub url: String,
+}
+
+/// Parse "YYYY-MM-DDTHH:MM:SS[.fraction](Z|±HH:MM)" into Unix seconds
+fn parse_rfc3339(s: &str) -> Option<i64> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || bytes[13] != b':' {
+        return None;
+    }
+    if !matches!(bytes[10], b'T' | b't' | b' ') || bytes[16] != b':' {
+        return None;
+    }
+
+    let num = |range: std::ops::Range<usize>| -> Option<i64> {
+        let part = s.get(range)?;
+        if part.bytes().all(|b| b.is_ascii_digit()) {
+            part.parse().ok()
+        } else {
+            None
+        }
+    };
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    if second > 60 {
+        return None;
+    }
+
+    // Skip fractional seconds
+    let mut rest = &s[19..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.bytes().take_while(|b| b.is_ascii_digit()).count();
+        rest = &fraction[digits..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let hours: i64 = rest.get(1..3)?.parse().ok()?;
+            let minutes: i64 = rest.get(4..6)?.parse().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+        _ => return None,
+    };
+
+    // Days from civil date (Howard Hinnant's algorithm)
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    Some(days * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_parsing() {
+        assert_eq!(
+            Timestamp::new("1970-01-01T00:00:00Z").unix_seconds(),
+            Some(0)
+        );
+        assert_eq!(
+            Timestamp::new("2024-02-29T12:30:45Z").unix_seconds(),
+            Some(1_709_209_845)
+        );
+        assert_eq!(
+            Timestamp::new("2024-02-29T14:30:45.123+02:00").unix_seconds(),
+            Some(1_709_209_845)
+        );
+        assert_eq!(
+            Timestamp::new("0001-01-01T00:00:00Z").unix_seconds(),
+            Some(-62_135_596_800)
+        );
+        assert_eq!(Timestamp::new("yesterday").unix_seconds(), None);
+        assert_eq!(Timestamp::new("2024-13-01T00:00:00Z").unix_seconds(), None);
+    }
+
+    #[test]
+    fn test_timestamp_ordering_and_display() {
+        let earlier = Timestamp::new("2024-01-01T00:00:00Z");
+        let later = Timestamp::new("2024-06-01T00:00:00Z");
+        assert!(earlier.to_system_time().unwrap() < later.to_system_time().unwrap());
+
+        // Earlier instant, but sorts last as a string
+        let offset = Timestamp::new("2024-01-01T01:00:00+02:00");
+        let utc = Timestamp::new("2023-12-31T23:30:00Z");
+        assert!(offset.as_str() > utc.as_str());
+        assert!(offset.unix_seconds() < utc.unix_seconds());
+        assert_eq!(later.to_string(), "2024-06-01T00:00:00Z");
+    }
+}
@@ -0,0 +1,345 @@
+#![cfg(feature = "testing")]
+
+use gh_cli_rs::testing::{Rule, Scenario, NO_MATCH_EXIT_CODE, SCENARIO_ENV};
+use gh_cli_rs::{GhClient, GhError};
+use std::path::PathBuf;
+
+fn client_for(name: &str, scenario: Scenario) -> (GhClient, PathBuf) {
+    let path = std::env::temp_dir().join(format!("gh-cli-rs-{}-{}.json", name, std::process::id()));
+    scenario.save(&path).unwrap();
+
+    let client = GhClient::builder()
+        .gh_path(env!("CARGO_BIN_EXE_gh-fake"))
+        .env(SCENARIO_ENV, path.to_string_lossy())
+        .build();
+    (client, path)
+}
+
+#[test]
+fn test_fake_gh_serves_scenario() {
+    let (client, path) = client_for(
+        "serves",
+        Scenario::new()
+            .rule(Rule::new(&["--version"]).stdout("gh version 2.0.0 (fake)\n"))
+            .rule(Rule::new(&["pr", "list", "**"]).stdout("#1\tFix bug\n")),
+    );
+
+    assert!(client.check_installation().unwrap().contains("fake"));
+    assert_eq!(
+        client.pr().list().limit(5).execute().unwrap(),
+        "#1\tFix bug\n"
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_fake_gh_reports_failures() {
+    let (client, path) = client_for(
+        "failures",
+        Scenario::new().rule(
+            Rule::new(&["pr", "merge", "*", "**"])
+                .stderr("HTTP 405: not mergeable")
+                .exit_code(1),
+        ),
+    );
+
+    match client.pr().merge(7).squash().execute() {
+        Err(GhError::CommandFailed { code, stderr }) => {
+            assert_eq!(code, 1);
+            assert!(stderr.contains("not mergeable"));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    match client.issue().list().execute() {
+        Err(GhError::CommandFailed { code, .. }) => assert_eq!(code, NO_MATCH_EXIT_CODE),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_fake_gh_offline_attestation_verify() {
+    let output = r#"[{"verificationResult": {
+        "statement": {"subject": [{"name": "app.tar.gz", "digest": {"sha256": "abc123"}}],
+                      "predicateType": "https://slsa.dev/provenance/v1"}}}]"#;
+    let (client, path) = client_for(
+        "attestation",
+        Scenario::new().rule(
+            Rule::new(&[
+                "attestation",
+                "verify",
+                "app.tar.gz",
+                "--repo",
+                "octo/app",
+                "--bundle",
+                "bundle.jsonl",
+                "--format",
+                "json",
+            ])
+            .stdout(output),
+        ),
+    );
+
+    let verified = client
+        .attestation()
+        .verify("app.tar.gz")
+        .repo("octo/app")
+        .bundle("bundle.jsonl")
+        .fetch()
+        .unwrap();
+    assert_eq!(
+        verified[0].verification_result.statement.subject[0].name,
+        "app.tar.gz"
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_fake_gh_fork_reports_existing_fork() {
+    // GitHub answers with the user's existing fork, under its own name
+    let (client, path) = client_for(
+        "fork",
+        Scenario::new()
+            .rule(
+                Rule::new(&["api", "-X", "POST", "repos/cli/cli/forks", "**"])
+                    .stdout(r#"{"name": "cli-fork", "owner": {"login": "monalisa"}}"#),
+            )
+            .rule(Rule::new(&["repo", "fork", "cli/cli", "--clone"])),
+    );
+
+    let fork = client
+        .repo()
+        .fork("cli/cli")
+        .fork_name("gh")
+        .clone()
+        .execute()
+        .unwrap();
+    assert_eq!(fork.full_name(), "monalisa/cli-fork");
+    assert_eq!(fork.local_path, Some(PathBuf::from("cli-fork")));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_fake_gh_paginated_listing() {
+    // `gh api --paginate` prints each page as a separate JSON array
+    let pages = concat!(
+        r#"[{"id": 1, "login": "octo"}, {"id": 2, "login": "hubot"}]"#,
+        "\n",
+        r#"[{"id": 3, "login": "monalisa"}]"#,
+        "\n",
+    );
+    let (client, path) = client_for(
+        "paginated",
+        Scenario::new()
+            .rule(Rule::new(&["api", "--paginate", "user/orgs?per_page=100"]).stdout(pages))
+            .rule(
+                Rule::new(&["api", "user/orgs?per_page=2&page=1"])
+                    .stdout(r#"[{"id": 1, "login": "octo"}, {"id": 2, "login": "hubot"}]"#),
+            ),
+    );
+
+    let logins: Vec<String> = client
+        .org()
+        .list()
+        .fetch()
+        .unwrap()
+        .into_iter()
+        .map(|org| org.login)
+        .collect();
+    assert_eq!(logins, vec!["octo", "hubot", "monalisa"]);
+    assert_eq!(client.org().list().limit(2).fetch().unwrap().len(), 2);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_fake_gh_status_sections() {
+    let result = |number: u32, repo: &str| {
+        format!(
+            r#"[{{"number": {}, "title": "t", "state": "open", "url": "u",
+                "author": {{"login": "hubot"}},
+                "repository": {{"name": "app", "nameWithOwner": "{}"}},
+                "createdAt": "2024-05-01T10:00:00Z", "updatedAt": "2024-05-01T10:00:00Z"}}]"#,
+            number, repo
+        )
+    };
+    // Each search is scoped to the org and leaves out the excluded repo
+    let search = |kind: &str, filter: &str| {
+        Rule::new(&[
+            "search",
+            kind,
+            filter,
+            "@me",
+            "--state",
+            "open",
+            "--limit",
+            "50",
+            "--owner",
+            "octo",
+            "--json",
+            "*",
+            "--",
+            "-repo:octo/noisy",
+        ])
+    };
+    let notifications = r#"[
+        {"id": "1", "reason": "mention", "updated_at": "2024-05-01T10:00:00Z",
+         "subject": {"title": "Ping", "type": "Issue"}, "repository": {"full_name": "octo/app"}},
+        {"id": "2", "reason": "mention", "updated_at": "2024-05-01T10:00:00Z",
+         "subject": {"title": "Noise", "type": "Issue"}, "repository": {"full_name": "octo/noisy"}},
+        {"id": "3", "reason": "author", "updated_at": "2024-05-01T10:00:00Z",
+         "subject": {"title": "Mine", "type": "Issue"}, "repository": {"full_name": "octo/app"}}
+    ]"#;
+    let events = r#"[
+        {"id": "10", "type": "IssueCommentEvent", "actor": {"login": "hubot"},
+         "repo": {"name": "octo/app"}, "created_at": "2024-05-01T10:00:00Z"},
+        {"id": "11", "type": "IssueCommentEvent", "actor": {"login": "monalisa"},
+         "repo": {"name": "octo/app"}, "created_at": "2024-05-01T10:00:00Z"},
+        {"id": "12", "type": "WatchEvent", "actor": {"login": "hubot"},
+         "repo": {"name": "octo/app"}, "created_at": "2024-05-01T10:00:00Z"},
+        {"id": "13", "type": "PullRequestEvent", "actor": {"login": "hubot"},
+         "repo": {"name": "other/app"}, "created_at": "2024-05-01T10:00:00Z"}
+    ]"#;
+    let (client, path) = client_for(
+        "status",
+        Scenario::new()
+            .rule(search("issues", "--assignee").stdout(&result(1, "octo/app")))
+            .rule(search("prs", "--assignee").stdout(&result(2, "octo/app")))
+            .rule(search("prs", "--review-requested").stdout(&result(3, "octo/lib")))
+            .rule(
+                Rule::new(&[
+                    "api",
+                    "notifications?all=true&participating=true&per_page=100&page=1",
+                ])
+                .stdout(notifications),
+            )
+            .rule(Rule::new(&["api", "user", "--jq", ".login"]).stdout("monalisa\n"))
+            .rule(
+                Rule::new(&["api", "users/monalisa/received_events?per_page=100&page=1"])
+                    .stdout(events),
+            ),
+    );
+
+    let status = client
+        .status()
+        .org("octo")
+        .exclude("octo/noisy")
+        .fetch()
+        .unwrap();
+    assert_eq!(status.assigned_issues[0].number, 1);
+    assert_eq!(status.assigned_prs[0].number, 2);
+    assert_eq!(status.review_requests[0].number, 3);
+    let mentions: Vec<&str> = status.mentions.iter().map(|n| n.id.as_str()).collect();
+    assert_eq!(mentions, vec!["1"]);
+    let activity: Vec<&str> = status
+        .repository_activity
+        .iter()
+        .map(|e| e.id.as_str())
+        .collect();
+    assert_eq!(activity, vec!["10"]);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_fake_gh_urls_never_open_a_browser() {
+    // None of the rules accept `--web`, so a leaked flag fails the lookup
+    let json_url = |args: &[&str]| {
+        let mut pattern = args.to_vec();
+        pattern.extend(["--json", "url", "--jq", ".url"]);
+        Rule::new(&pattern)
+    };
+    let (client, path) = client_for(
+        "urls",
+        Scenario::new()
+            .rule(
+                json_url(&["issue", "view", "7"]).stdout("https://github.com/octo/app/issues/7\n"),
+            )
+            .rule(json_url(&["pr", "view", "8"]).stdout("https://github.com/octo/app/pull/8\n"))
+            .rule(json_url(&["repo", "view", "octo/app"]).stdout("https://github.com/octo/app\n"))
+            .rule(json_url(&["repo", "view"]).stdout("https://github.com/octo/app\n"))
+            .rule(
+                Rule::new(&["repo", "view", "--json", "defaultBranchRef", "--jq", "*"])
+                    .stdout("main\n"),
+            )
+            .rule(
+                Rule::new(&[
+                    "project", "view", "12", "--owner", "@me", "--format", "json", "--jq", ".url",
+                ])
+                .stdout("https://github.com/users/monalisa/projects/12\n"),
+            )
+            .rule(
+                Rule::new(&["api", "gists/abc123", "--jq", ".html_url"])
+                    .stdout("https://gist.github.com/abc123\n"),
+            )
+            .rule(
+                Rule::new(&["browse", "--no-browser", "--repo", "octo/app", "--wiki"])
+                    .stdout("https://github.com/octo/app/wiki\n"),
+            ),
+    );
+
+    assert_eq!(
+        client.issue().view(7).web().url().unwrap(),
+        "https://github.com/octo/app/issues/7"
+    );
+    assert_eq!(
+        client.pr().view(8).web().url().unwrap(),
+        "https://github.com/octo/app/pull/8"
+    );
+    assert_eq!(
+        client.repo().view(Some("octo/app")).web().url().unwrap(),
+        "https://github.com/octo/app"
+    );
+    assert_eq!(
+        client.project().view(12).web().url().unwrap(),
+        "https://github.com/users/monalisa/projects/12"
+    );
+    assert_eq!(
+        client.gist().view("abc123").web().url().unwrap(),
+        "https://gist.github.com/abc123"
+    );
+    assert_eq!(
+        client.browse().repo("octo/app").wiki().url().unwrap(),
+        "https://github.com/octo/app/wiki"
+    );
+
+    // Create forms are prefilled rather than submitted
+    assert_eq!(
+        client
+            .issue()
+            .create()
+            .title("Crash on start")
+            .label("bug")
+            .label("p1")
+            .web()
+            .url()
+            .unwrap(),
+        "https://github.com/octo/app/issues/new?title=Crash%20on%20start&labels=bug%2Cp1"
+    );
+    assert_eq!(
+        client
+            .pr()
+            .create()
+            .head("fix/crash")
+            .title("Fix crash")
+            .web()
+            .url()
+            .unwrap(),
+        "https://github.com/octo/app/compare/main...fix/crash?expand=1&title=Fix%20crash"
+    );
+    assert!(matches!(
+        client.pr().create().web().url(),
+        Err(GhError::InvalidCommand(_))
+    ));
+    assert!(matches!(
+        client.pr().create().head("fix/crash").draft().web().url(),
+        Err(GhError::InvalidCommand(_))
+    ));
+
+    std::fs::remove_file(path).unwrap();
+}